@@ -1,2 +1,3 @@
 pub mod physics;
+pub mod simulation;
 pub mod stellar_objects;
@@ -0,0 +1,80 @@
+use crate::physics::constants::MIN_LAGRANGE_MASS_RATIO;
+use crate::physics::units::*;
+
+/// Anzahl der Bisektionsschritte; genügt für volle f64-Genauigkeit im Intervall [-2, 2].
+const BISECTION_STEPS: usize = 200;
+
+/// Die kollinearen Lagrange-Punkte L1, L2 und L3 eines Zweikörpersystems auf
+/// Kreisbahn (eingeschränktes Dreikörperproblem).
+#[derive(Debug, Clone, Copy)]
+pub struct LagrangeSystem {
+    /// Massenparameter μ = m₂ / (m₁ + m₂).
+    pub mass_parameter: f64,
+    /// Abstand von L1 zum Sekundärkörper, zwischen beiden Körpern.
+    pub l1_distance: Distance<Meter>,
+    /// Abstand von L2 zum Sekundärkörper, auf dessen Außenseite.
+    pub l2_distance: Distance<Meter>,
+    /// Abstand von L3 zum Primärkörper, gegenüber dem Sekundärkörper.
+    pub l3_distance: Distance<Meter>,
+}
+
+impl LagrangeSystem {
+    /// Löst die Gleichgewichtsbedingung auf der Verbindungslinie numerisch.
+    ///
+    /// Im mitrotierenden, normierten System liegen die Körper bei x = −μ und
+    /// x = 1 − μ. Die Lagrange-Punkte sind die Nullstellen von
+    /// f(x) = x − (1−μ)(x+μ)/|x+μ|³ − μ(x−1+μ)/|x−1+μ|³, die zwischen den
+    /// Singularitäten streng monoton ist und daher per Bisektion gefunden wird.
+    pub fn new<P, S, D>(
+        primary_mass: Mass<P>,
+        secondary_mass: Mass<S>,
+        separation: Distance<D>,
+    ) -> Self
+    where
+        Mass<P>: ToSI,
+        Mass<S>: ToSI,
+        Distance<D>: ToSI,
+    {
+        let m1 = primary_mass.to_si();
+        let m2 = secondary_mass.to_si();
+        let mu = m2 / (m1 + m2);
+        let a = separation.to_si();
+
+        let gradient = |x: f64| {
+            let r1 = x + mu;
+            let r2 = x - 1.0 + mu;
+            x - (1.0 - mu) * r1 / r1.abs().powi(3) - mu * r2 / r2.abs().powi(3)
+        };
+        let bisect = |mut low: f64, mut high: f64| {
+            for _ in 0..BISECTION_STEPS {
+                let mid = 0.5 * (low + high);
+                if gradient(mid) < 0.0 {
+                    low = mid;
+                } else {
+                    high = mid;
+                }
+            }
+            0.5 * (low + high)
+        };
+
+        let primary = -mu;
+        let secondary = 1.0 - mu;
+        let gap = 1e-12;
+        let l1 = bisect(primary + gap, secondary - gap);
+        let l2 = bisect(secondary + gap, 2.0);
+        let l3 = bisect(-2.0, primary - gap);
+
+        Self {
+            mass_parameter: mu,
+            l1_distance: Distance::<Meter>::new((secondary - l1) * a),
+            l2_distance: Distance::<Meter>::new((l2 - secondary) * a),
+            l3_distance: Distance::<Meter>::new((primary - l3) * a),
+        }
+    }
+
+    /// Ob L4 und L5 stabil sind: Der Primärkörper muss mindestens 24.96-mal
+    /// schwerer sein als der Sekundärkörper.
+    pub fn has_stable_trojan_points(&self) -> bool {
+        (1.0 - self.mass_parameter) / self.mass_parameter >= f64::from(MIN_LAGRANGE_MASS_RATIO)
+    }
+}
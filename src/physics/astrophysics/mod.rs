@@ -0,0 +1,13 @@
+pub mod lagrange_points;
+pub mod roche;
+pub mod stability;
+pub mod stellar;
+pub mod tidal;
+pub mod transit;
+
+pub use lagrange_points::*;
+pub use roche::*;
+pub use stability::*;
+pub use stellar::*;
+pub use tidal::*;
+pub use transit::*;
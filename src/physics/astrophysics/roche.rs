@@ -0,0 +1,43 @@
+use crate::physics::units::*;
+
+/// Die mittlere Dichte des Primärkörpers in kg/m³.
+fn primary_density<M, D>(primary_mass: Mass<M>, primary_radius: &Distance<D>) -> f64
+where
+    Mass<M>: ToSI,
+    Distance<D>: ToSI,
+{
+    primary_mass.to_si() / (4.0 / 3.0 * std::f64::consts::PI * primary_radius.to_si().powi(3))
+}
+
+/// Die Roche-Grenze für einen starren Begleiter der Dichte `satellite_density`
+/// (kg/m³): d = R · (2·ρ_P/ρ_S)^(1/3).
+///
+/// Innerhalb dieses Abstands zerreißen Gezeitenkräfte einen Begleiter, der nur
+/// durch seine eigene Schwerkraft zusammengehalten wird.
+pub fn roche_limit<M, D>(
+    primary_mass: Mass<M>,
+    primary_radius: Distance<D>,
+    satellite_density: f64,
+) -> Distance<Meter>
+where
+    Mass<M>: ToSI,
+    Distance<D>: ToSI,
+{
+    let density_ratio = primary_density(primary_mass, &primary_radius) / satellite_density;
+    Distance::<Meter>::new(primary_radius.to_si() * (2.0 * density_ratio).cbrt())
+}
+
+/// Die Roche-Grenze für einen flüssigen, sich verformenden Begleiter:
+/// d = 2.44 · R · (ρ_P/ρ_S)^(1/3).
+pub fn fluid_roche_limit<M, D>(
+    primary_mass: Mass<M>,
+    primary_radius: Distance<D>,
+    satellite_density: f64,
+) -> Distance<Meter>
+where
+    Mass<M>: ToSI,
+    Distance<D>: ToSI,
+{
+    let density_ratio = primary_density(primary_mass, &primary_radius) / satellite_density;
+    Distance::<Meter>::new(2.44 * primary_radius.to_si() * density_ratio.cbrt())
+}
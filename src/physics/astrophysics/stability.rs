@@ -0,0 +1,64 @@
+use crate::physics::units::*;
+
+/// Das kritische Verhältnis R_p,out / a_in nach Mardling & Aarseth (2001), oberhalb
+/// dessen ein hierarchisches Dreifachsystem langfristig stabil bleibt:
+/// 2.8 · [(1 + q_out)(1 + e_out) / √(1 − e_out)]^(2/5) · (1 − 0.3·i/π).
+///
+/// Das ist die Periapsis-Form des Kriteriums; die bekanntere Form für
+/// a_out / a_in enthält zusätzlich den Faktor (1 − e_out)^(−1), also insgesamt
+/// (1 − e_out)^(−6/5).
+///
+/// `inner_mass` ist die Gesamtmasse des inneren Paars, `outer_mass` die des
+/// äußeren Begleiters (q_out = m₃ / (m₁ + m₂)) und `mutual_inclination` die
+/// Neigung zwischen innerer und äußerer Bahn.
+pub fn mardling_aarseth_critical_ratio<A, B, R>(
+    inner_mass: Mass<A>,
+    outer_mass: Mass<B>,
+    outer_eccentricity: f64,
+    mutual_inclination: Angle<R>,
+) -> f64
+where
+    Mass<A>: ToSI,
+    Mass<B>: ToSI,
+    Angle<R>: ToSI,
+{
+    let mass_ratio = outer_mass.to_si() / inner_mass.to_si();
+    let e = outer_eccentricity;
+    let inclination_factor = 1.0 - 0.3 * mutual_inclination.to_si() / std::f64::consts::PI;
+    2.8 * (1.0 + mass_ratio).powf(0.4)
+        * (1.0 + e).powf(0.4)
+        * (1.0 - e).powf(-0.2)
+        * inclination_factor
+}
+
+/// Prüft ein hierarchisches Dreifachsystem mit dem Kriterium von Mardling &
+/// Aarseth: die äußere Periapsis a_out·(1 − e_out) muss mindestens das kritische
+/// Vielfache der inneren großen Halbachse betragen.
+pub fn is_hierarchical_triple_stable<A, B, I, O, R>(
+    inner_mass: Mass<A>,
+    outer_mass: Mass<B>,
+    inner_semi_major_axis: Distance<I>,
+    outer_semi_major_axis: Distance<O>,
+    outer_eccentricity: f64,
+    mutual_inclination: Angle<R>,
+) -> bool
+where
+    Mass<A>: ToSI,
+    Mass<B>: ToSI,
+    Distance<I>: ToSI,
+    Distance<O>: ToSI,
+    Angle<R>: ToSI,
+{
+    if outer_eccentricity >= 1.0 {
+        return false;
+    }
+    let periapsis_ratio =
+        outer_semi_major_axis.to_si() * (1.0 - outer_eccentricity) / inner_semi_major_axis.to_si();
+    periapsis_ratio
+        > mardling_aarseth_critical_ratio(
+            inner_mass,
+            outer_mass,
+            outer_eccentricity,
+            mutual_inclination,
+        )
+}
@@ -0,0 +1,13 @@
+use crate::physics::units::*;
+
+/// Hauptreihen-Lebensdauer der Sonne in Gigajahren.
+const SOLAR_MAIN_SEQUENCE_LIFETIME_GYR: f64 = 10.0;
+
+/// Genäherte Hauptreihen-Lebensdauer eines Sterns der Masse `mass_solar` (in M☉).
+///
+/// Verwendet die Masse-Lebensdauer-Beziehung t ≈ 10 Gyr · (M/M☉)^-2.5. Gedacht als
+/// schneller Vorfilter für große Populationen, bevor vollständige Sterndaten
+/// berechnet werden.
+pub fn approximate_main_sequence_lifetime(mass_solar: f64) -> Time<Gigayear> {
+    Time::<Gigayear>::new(SOLAR_MAIN_SEQUENCE_LIFETIME_GYR * mass_solar.powf(-2.5))
+}
@@ -0,0 +1,29 @@
+use crate::physics::constants::G;
+use crate::physics::units::*;
+
+/// Gezeitenheizleistung eines synchron rotierenden Körpers auf exzentrischer Bahn.
+///
+/// Ė = 21/2 · (k₂/Q) · G·M²·R⁵·n·e² / a⁶ (Peale et al. 1979, Segatz et al. 1988), mit
+/// der Masse `host_mass` des Zentralkörpers, dem Radius `radius` des geheizten
+/// Körpers und dem Verhältnis `k2_over_q` aus Love-Zahl und Gezeiten-Qualitätsfaktor.
+pub fn calculate_tidal_heating<M, R, A>(
+    host_mass: Mass<M>,
+    radius: Distance<R>,
+    semi_major_axis: Distance<A>,
+    eccentricity: f64,
+    k2_over_q: f64,
+) -> Power<Watt>
+where
+    Mass<M>: ToSI,
+    Distance<R>: ToSI,
+    Distance<A>: ToSI,
+{
+    let g = f64::from(G);
+    let m = host_mass.to_si();
+    let r = radius.to_si();
+    let a = semi_major_axis.to_si();
+    let n = (g * m / a.powi(3)).sqrt();
+    Power::<Watt>::new(
+        21.0 / 2.0 * k2_over_q * g * m * m * r.powi(5) * n * eccentricity.powi(2) / a.powi(6),
+    )
+}
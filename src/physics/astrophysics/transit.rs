@@ -0,0 +1,12 @@
+use crate::physics::units::*;
+
+/// Die relative Verdunkelung (R_p / R_s)², wenn ein Planet mit dem Radius
+/// `planet_radius` vollständig vor einem Stern mit dem Radius `star_radius`
+/// vorüberzieht.
+pub fn transit_depth<S, P>(star_radius: Distance<S>, planet_radius: Distance<P>) -> f64
+where
+    Distance<S>: ToSI,
+    Distance<P>: ToSI,
+{
+    (planet_radius.to_si() / star_radius.to_si()).powi(2)
+}
@@ -1,6 +1,9 @@
 /// Gravitationskonstante (m³ kg⁻¹ s⁻²)
 pub const G: f32 = 6.67430e-11;
 
+/// Gaußsche Gravitationskonstante k (AE^(3/2) M☉^(-1/2) Tag⁻¹), k² = G·M☉ in AE³/Tag²
+pub const GAUSSIAN_GRAVITATIONAL_CONSTANT: f32 = 0.017_202_1;
+
 /// Lichtgeschwindigkeit in m/s
 pub const SPEED_OF_LIGHT: f32 = 2.99792458e8;
 
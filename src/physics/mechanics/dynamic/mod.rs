@@ -0,0 +1,3 @@
+pub mod nbody;
+
+pub use nbody::*;
@@ -0,0 +1,83 @@
+use crate::physics::constants::G;
+
+/// Ein Massenpunkt der N-Körper-Integration in SI-Einheiten (kg, m, m/s).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointMass {
+    pub mass: f64,
+    pub position: [f64; 3],
+    pub velocity: [f64; 3],
+}
+
+/// Die Newtonschen Gravitationsbeschleunigungen aller Körper aufeinander.
+fn accelerations(bodies: &[PointMass]) -> Vec<[f64; 3]> {
+    let g = f64::from(G);
+    let mut accelerations = vec![[0.0; 3]; bodies.len()];
+    for i in 0..bodies.len() {
+        for j in (i + 1)..bodies.len() {
+            let delta: [f64; 3] =
+                std::array::from_fn(|k| bodies[j].position[k] - bodies[i].position[k]);
+            let distance_sq = delta.iter().map(|d| d * d).sum::<f64>();
+            let inverse_cube = 1.0 / (distance_sq * distance_sq.sqrt());
+            for k in 0..3 {
+                accelerations[i][k] += g * bodies[j].mass * delta[k] * inverse_cube;
+                accelerations[j][k] -= g * bodies[i].mass * delta[k] * inverse_cube;
+            }
+        }
+    }
+    accelerations
+}
+
+/// Ein Kick-Drift-Kick-Leapfrog-Schritt der Länge `dt` in Sekunden.
+///
+/// Das Verfahren ist symplektisch: der Energiefehler wächst nicht säkular,
+/// sondern schwankt beschränkt um den Anfangswert.
+pub fn leapfrog_step(bodies: &mut [PointMass], dt: f64) {
+    let start = accelerations(bodies);
+    for (body, acceleration) in bodies.iter_mut().zip(start) {
+        for ((velocity, position), a) in body
+            .velocity
+            .iter_mut()
+            .zip(&mut body.position)
+            .zip(acceleration)
+        {
+            *velocity += 0.5 * dt * a;
+            *position += dt * *velocity;
+        }
+    }
+    let end = accelerations(bodies);
+    for (body, acceleration) in bodies.iter_mut().zip(end) {
+        for (velocity, a) in body.velocity.iter_mut().zip(acceleration) {
+            *velocity += 0.5 * dt * a;
+        }
+    }
+}
+
+/// Die Gesamtenergie (kinetisch plus potentiell) des Systems in J.
+pub fn total_energy(bodies: &[PointMass]) -> f64 {
+    let g = f64::from(G);
+    let kinetic: f64 = bodies
+        .iter()
+        .map(|body| 0.5 * body.mass * body.velocity.iter().map(|v| v * v).sum::<f64>())
+        .sum();
+    let mut potential = 0.0;
+    for i in 0..bodies.len() {
+        for j in (i + 1)..bodies.len() {
+            let distance = (0..3)
+                .map(|k| (bodies[j].position[k] - bodies[i].position[k]).powi(2))
+                .sum::<f64>()
+                .sqrt();
+            potential -= g * bodies[i].mass * bodies[j].mass / distance;
+        }
+    }
+    kinetic + potential
+}
+
+/// Der Gesamtdrehimpuls Σ m·(r × v) des Systems in kg·m²/s.
+pub fn total_angular_momentum(bodies: &[PointMass]) -> [f64; 3] {
+    bodies.iter().fold([0.0; 3], |total, body| {
+        let [x, y, z] = body.position;
+        let [vx, vy, vz] = body.velocity;
+        let moment = [y * vz - z * vy, z * vx - x * vz, x * vy - y * vx];
+        std::array::from_fn(|k| total[k] + body.mass * moment[k])
+    })
+}
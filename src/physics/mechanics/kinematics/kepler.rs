@@ -0,0 +1,109 @@
+use std::f64::consts::PI;
+
+/// Abbruchgenauigkeit der Newton-Iteration für die exzentrische Anomalie.
+const KEPLER_TOLERANCE: f64 = 1e-10;
+/// Maximale Anzahl an Newton-Schritten.
+const KEPLER_MAX_ITERATIONS: usize = 50;
+
+/// Löst die Kepler-Gleichung E − e·sin(E) = M nach der exzentrischen Anomalie E.
+///
+/// Newton-Raphson-Iteration bis |ΔE| < 1e-10 oder 50 Schritte. Für hohe
+/// Exzentrizitäten startet die Iteration bei E = π, wo sie zuverlässig konvergiert.
+pub fn solve_kepler(mean_anomaly: f64, eccentricity: f64) -> f64 {
+    let m = mean_anomaly.rem_euclid(2.0 * PI);
+    let mut e_anomaly = if eccentricity < 0.8 { m } else { PI };
+    for _ in 0..KEPLER_MAX_ITERATIONS {
+        let delta = (e_anomaly - eccentricity * e_anomaly.sin() - m)
+            / (1.0 - eccentricity * e_anomaly.cos());
+        e_anomaly -= delta;
+        if delta.abs() < KEPLER_TOLERANCE {
+            break;
+        }
+    }
+    e_anomaly
+}
+
+/// Die wahre Anomalie zur exzentrischen Anomalie `eccentric_anomaly`, im Bereich [0, 2π).
+pub fn true_anomaly_from_eccentric(eccentric_anomaly: f64, eccentricity: f64) -> f64 {
+    let half = eccentric_anomaly / 2.0;
+    let nu = 2.0
+        * ((1.0 + eccentricity).sqrt() * half.sin())
+            .atan2((1.0 - eccentricity).sqrt() * half.cos());
+    nu.rem_euclid(2.0 * PI)
+}
+
+/// Newton-Iteration der hyperbolischen Kepler-Gleichung e·sinh(H) − H = M.
+fn solve_hyperbolic_kepler(mean_anomaly: f64, eccentricity: f64) -> f64 {
+    let mut h_anomaly = (mean_anomaly / eccentricity).asinh();
+    for _ in 0..KEPLER_MAX_ITERATIONS {
+        let delta = (eccentricity * h_anomaly.sinh() - h_anomaly - mean_anomaly)
+            / (eccentricity * h_anomaly.cosh() - 1.0);
+        h_anomaly -= delta;
+        if delta.abs() < KEPLER_TOLERANCE {
+            break;
+        }
+    }
+    h_anomaly
+}
+
+/// Die exzentrische Anomalie zur wahren Anomalie `true_anomaly`.
+///
+/// Für e > 1 ist das Ergebnis die hyperbolische Anomalie H, für e = 1 die
+/// parabolische Anomalie D = tan(ν/2). Elliptische Werte liegen in [0, 2π).
+pub fn true_to_eccentric_anomaly(true_anomaly: f64, eccentricity: f64) -> f64 {
+    let half = true_anomaly / 2.0;
+    if eccentricity < 1.0 {
+        let e_anomaly = 2.0
+            * ((1.0 - eccentricity).sqrt() * half.sin())
+                .atan2((1.0 + eccentricity).sqrt() * half.cos());
+        e_anomaly.rem_euclid(2.0 * PI)
+    } else if eccentricity > 1.0 {
+        2.0 * (((eccentricity - 1.0) / (eccentricity + 1.0)).sqrt() * half.tan()).atanh()
+    } else {
+        half.tan()
+    }
+}
+
+/// Die wahre Anomalie zur exzentrischen (bzw. hyperbolischen oder parabolischen)
+/// Anomalie, die Umkehrung von [`true_to_eccentric_anomaly`].
+pub fn eccentric_to_true_anomaly(eccentric_anomaly: f64, eccentricity: f64) -> f64 {
+    if eccentricity < 1.0 {
+        true_anomaly_from_eccentric(eccentric_anomaly, eccentricity)
+    } else if eccentricity > 1.0 {
+        2.0 * (((eccentricity + 1.0) / (eccentricity - 1.0)).sqrt()
+            * (eccentric_anomaly / 2.0).tanh())
+        .atan()
+    } else {
+        2.0 * eccentric_anomaly.atan()
+    }
+}
+
+/// Die mittlere Anomalie nach der Kepler-Gleichung: M = E − e·sin(E) für
+/// Ellipsen, M = e·sinh(H) − H für Hyperbeln und Barkers M = D + D³/3 für
+/// Parabeln.
+pub fn eccentric_to_mean_anomaly(eccentric_anomaly: f64, eccentricity: f64) -> f64 {
+    if eccentricity < 1.0 {
+        eccentric_anomaly - eccentricity * eccentric_anomaly.sin()
+    } else if eccentricity > 1.0 {
+        eccentricity * eccentric_anomaly.sinh() - eccentric_anomaly
+    } else {
+        eccentric_anomaly + eccentric_anomaly.powi(3) / 3.0
+    }
+}
+
+/// Die exzentrische Anomalie zur mittleren Anomalie, die Umkehrung von
+/// [`eccentric_to_mean_anomaly`].
+///
+/// Ellipsen werden mit [`solve_kepler`] gelöst, Hyperbeln mit der analogen
+/// Newton-Iteration; Barkers Gleichung hat eine geschlossene Lösung.
+pub fn mean_to_eccentric_anomaly(mean_anomaly: f64, eccentricity: f64) -> f64 {
+    if eccentricity < 1.0 {
+        solve_kepler(mean_anomaly, eccentricity)
+    } else if eccentricity > 1.0 {
+        solve_hyperbolic_kepler(mean_anomaly, eccentricity)
+    } else {
+        let w = 1.5 * mean_anomaly;
+        let y = (w + (w * w + 1.0).sqrt()).cbrt();
+        y - 1.0 / y
+    }
+}
@@ -0,0 +1,3 @@
+pub mod kepler;
+
+pub use kepler::*;
@@ -1,4 +1,4 @@
-// pub mod astrophysics;
+pub mod astrophysics;
 pub mod constants;
 pub mod mechanics;
 pub mod statics;
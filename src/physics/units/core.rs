@@ -28,7 +28,9 @@
 //! ```
 
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fmt;
+use std::iter::Sum;
 use std::marker::PhantomData;
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
@@ -114,6 +116,7 @@ pub struct Quantity<
     const N: i8,
 > {
     /// The numerical value of this quantity in the specified unit
+    #[serde(with = "crate::physics::units::safe_f64")]
     pub value: f64,
     /// Phantom data to track the unit type at compile time
     _unit: PhantomData<Unit>,
@@ -308,6 +311,34 @@ impl<
         let si_value = self.to_si();
         Quantity::<ToUnit, L, M, T, K, I, J, N>::from_si(si_value)
     }
+
+    /// Check whether two quantities agree within a relative tolerance.
+    ///
+    /// The SI values are compared, so `rel_tol` is a fraction of the larger
+    /// magnitude. Identical values (including two zeros) are always equal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use star_sim::physics::units::*;
+    ///
+    /// let a = Distance::<AstronomicalUnit>::new(1.0);
+    /// let b = Distance::<AstronomicalUnit>::new(1.001);
+    /// assert!(a.approx_eq(&b, 1e-2));
+    /// assert!(!a.approx_eq(&b, 1e-4));
+    /// ```
+    pub fn approx_eq(&self, other: &Self, rel_tol: f64) -> bool
+    where
+        Self: ToSI,
+    {
+        let (a, b) = (self.to_si(), other.to_si());
+        a == b || (a - b).abs() <= rel_tol * a.abs().max(b.abs())
+    }
+
+    /// The absolute difference between two quantities, in this quantity's unit.
+    pub fn abs_diff(&self, other: &Self) -> Self {
+        Self::new((self.value - other.value).abs())
+    }
 }
 
 impl<
@@ -421,9 +452,118 @@ impl<
     }
 }
 
-// For now, we'll skip automatic dimensional analysis via multiplication/division
-// This feature requires const generic arithmetic which is not yet stable in Rust
-// Instead, we'll provide explicit functions for common operations
+// Comparison (same unit and dimensions)
+impl<
+    Unit,
+    const L: i8,
+    const M: i8,
+    const T: i8,
+    const K: i8,
+    const I: i8,
+    const J: i8,
+    const N: i8,
+> PartialEq for Quantity<Unit, L, M, T, K, I, J, N>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<
+    Unit,
+    const L: i8,
+    const M: i8,
+    const T: i8,
+    const K: i8,
+    const I: i8,
+    const J: i8,
+    const N: i8,
+> PartialOrd for Quantity<Unit, L, M, T, K, I, J, N>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+// Summation over iterators (same dimensions)
+impl<
+    Unit,
+    const L: i8,
+    const M: i8,
+    const T: i8,
+    const K: i8,
+    const I: i8,
+    const J: i8,
+    const N: i8,
+> Sum for Quantity<Unit, L, M, T, K, I, J, N>
+{
+    fn sum<Iter: Iterator<Item = Self>>(iter: Iter) -> Self {
+        Self::new(iter.map(|quantity| quantity.value).sum())
+    }
+}
+
+impl<
+    'a,
+    Unit,
+    const L: i8,
+    const M: i8,
+    const T: i8,
+    const K: i8,
+    const I: i8,
+    const J: i8,
+    const N: i8,
+> Sum<&'a Self> for Quantity<Unit, L, M, T, K, I, J, N>
+{
+    fn sum<Iter: Iterator<Item = &'a Self>>(iter: Iter) -> Self {
+        Self::new(iter.map(|quantity| quantity.value).sum())
+    }
+}
+
+/// Extension trait for averaging iterators of quantities.
+///
+/// # Examples
+///
+/// ```rust
+/// use star_sim::physics::units::*;
+///
+/// let separations = [
+///     Distance::<AstronomicalUnit>::new(1.0),
+///     Distance::<AstronomicalUnit>::new(3.0),
+/// ];
+/// let mean = separations.into_iter().mean().unwrap();
+/// assert_eq!(mean.value(), 2.0);
+/// ```
+pub trait QuantityMean<Q> {
+    /// Returns the arithmetic mean, or `None` if the iterator is empty.
+    fn mean(self) -> Option<Q>;
+}
+
+impl<
+    Iter,
+    Unit,
+    const L: i8,
+    const M: i8,
+    const T: i8,
+    const K: i8,
+    const I: i8,
+    const J: i8,
+    const N: i8,
+> QuantityMean<Quantity<Unit, L, M, T, K, I, J, N>> for Iter
+where
+    Iter: Iterator<Item = Quantity<Unit, L, M, T, K, I, J, N>>,
+{
+    fn mean(self) -> Option<Quantity<Unit, L, M, T, K, I, J, N>> {
+        let (count, total) = self.fold((0usize, 0.0), |(count, total), quantity| {
+            (count + 1, total + quantity.value)
+        });
+        (count > 0).then(|| Quantity::new(total / count as f64))
+    }
+}
+
+// Generic dimensional analysis via multiplication/division would need const
+// generic arithmetic, which is not yet stable in Rust. The common combinations
+// are implemented in `dimensions` (e.g. Distance / Time = Velocity); the
+// functions below cover everything else and return raw SI values.
 
 // Helper function for multiplying quantities - returns result in SI units
 pub fn multiply_quantities<
@@ -483,6 +623,80 @@ where
     q1.to_si() / q2.to_si()
 }
 
+/// Error returned when a quantity cannot be parsed from a string such as `"1.5 AU"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseQuantityError {
+    /// The input has no unit symbol after the number.
+    MissingUnit(String),
+    /// The numeric part is not a valid `f64`.
+    InvalidNumber(String),
+    /// The symbol does not belong to any unit of the target dimension.
+    UnknownUnit(String),
+}
+
+impl fmt::Display for ParseQuantityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseQuantityError::MissingUnit(input) => {
+                write!(f, "missing unit symbol in '{}'", input)
+            }
+            ParseQuantityError::InvalidNumber(number) => {
+                write!(f, "invalid number '{}'", number)
+            }
+            ParseQuantityError::UnknownUnit(symbol) => write!(f, "unknown unit '{}'", symbol),
+        }
+    }
+}
+
+impl std::error::Error for ParseQuantityError {}
+
+/// Splits a string like `"1.5 AU"` into its value and unit and looks the unit up.
+///
+/// # Parameters
+///
+/// - `input`: A number followed by whitespace and a unit symbol
+/// - `units`: The `(symbol, SI factor)` pairs of the target dimension, as produced
+///   by the `unit_table()` function generated by `define_unit_dimension!`
+///
+/// # Returns
+///
+/// The numeric value in the parsed unit and that unit's factor to SI base units.
+pub fn parse_value_and_unit(
+    input: &str,
+    units: &[(&'static str, f64)],
+) -> Result<(f64, f64), ParseQuantityError> {
+    let (number, symbol) = input
+        .trim()
+        .split_once(char::is_whitespace)
+        .ok_or_else(|| ParseQuantityError::MissingUnit(input.to_string()))?;
+    let value = number
+        .parse::<f64>()
+        .map_err(|_| ParseQuantityError::InvalidNumber(number.to_string()))?;
+    let symbol = symbol.trim();
+    units
+        .iter()
+        .find(|(unit_symbol, _)| *unit_symbol == symbol)
+        .map(|&(_, factor)| (value, factor))
+        .ok_or_else(|| ParseQuantityError::UnknownUnit(symbol.to_string()))
+}
+
+/// Formats an SI value in the largest unit of `units` whose value is at least 1.
+///
+/// Falls back to the smallest unit for values below 1 in every unit. Values are
+/// printed with two decimals, e.g. `"3.17 ly"` for 3e16 m.
+pub fn format_in_best_unit(si_value: f64, units: &[(&'static str, f64)]) -> String {
+    let mut sorted = units.to_vec();
+    sorted.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let (symbol, factor) = sorted
+        .iter()
+        .rev()
+        .find(|(_, factor)| (si_value / factor).abs() >= 1.0)
+        .or(sorted.first())
+        .copied()
+        .unwrap_or(("", 1.0));
+    format!("{:.2} {}", si_value / factor, symbol)
+}
+
 // Display implementation
 impl<
     Unit,
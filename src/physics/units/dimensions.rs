@@ -1,6 +1,7 @@
 use crate::physics::units::constants::*;
 use crate::physics::units::core::*;
 use crate::{define_quantity, define_unit_dimension};
+use std::str::FromStr;
 
 // Define basic quantity types using dimensional analysis
 define_quantity!(Distance, 1, 0, 0, 0, 0, 0, 0); // Length
@@ -30,6 +31,8 @@ define_quantity!(AngularAcceleration, 0, 0, -2, 0, 0, 0, 0); // 1/Time²
 
 // Additional derived quantities
 define_quantity!(Momentum, 1, 1, -1, 0, 0, 0, 0); // Mass×Length/Time
+define_quantity!(MassFlowRate, 0, 1, -1, 0, 0, 0, 0); // Mass/Time
+define_quantity!(AngularMomentum, 2, 1, -1, 0, 0, 0, 0); // Mass×Length²/Time
 
 // Define Distance units with astronomical focus
 
@@ -115,6 +118,50 @@ define_unit_dimension! {
     }
 }
 
+// Celsius and Fahrenheit have a zero point offset from Kelvin, so their
+// conversions cannot be generated as pure scale factors
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Celsius;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Fahrenheit;
+
+impl UnitSymbol for Celsius {
+    fn symbol() -> &'static str {
+        "°C"
+    }
+}
+
+impl UnitSymbol for Fahrenheit {
+    fn symbol() -> &'static str {
+        "°F"
+    }
+}
+
+impl ToSI for Temperature<Celsius> {
+    fn to_si(&self) -> f64 {
+        self.value + CELSIUS_OFFSET
+    }
+}
+
+impl FromSI for Temperature<Celsius> {
+    fn from_si(value: f64) -> Self {
+        Self::new(value - CELSIUS_OFFSET)
+    }
+}
+
+impl ToSI for Temperature<Fahrenheit> {
+    fn to_si(&self) -> f64 {
+        (self.value - FAHRENHEIT_OFFSET) * CELSIUS_PER_FAHRENHEIT + CELSIUS_OFFSET
+    }
+}
+
+impl FromSI for Temperature<Fahrenheit> {
+    fn from_si(value: f64) -> Self {
+        Self::new((value - CELSIUS_OFFSET) / CELSIUS_PER_FAHRENHEIT + FAHRENHEIT_OFFSET)
+    }
+}
+
 // Define Energy units
 define_unit_dimension! {
     dimension Energy {
@@ -138,10 +185,12 @@ define_unit_dimension! {
         base_unit: Watt = 1.0,
         units: {
             Watt = 1.0,
+            Kilowatt = 1000.0,
             SolarLuminosity = WATTS_PER_SOLAR_LUMINOSITY,
         },
         symbols: {
             Watt = "W",
+            Kilowatt = "kW",
             SolarLuminosity = "L☉",
         }
     }
@@ -229,10 +278,14 @@ define_unit_dimension! {
         units: {
             MeterPerSecond = 1.0,
             KilometerPerHour = 1000.0 / 3600.0,
+            KilometerPerSecond = 1000.0,
+            AstronomicalUnitPerYear = METERS_PER_AU / SECONDS_PER_YEAR,
         },
         symbols: {
             MeterPerSecond = "m/s",
             KilometerPerHour = "km/h",
+            KilometerPerSecond = "km/s",
+            AstronomicalUnitPerYear = "AU/yr",
         }
     }
 }
@@ -321,6 +374,34 @@ define_unit_dimension! {
     }
 }
 
+// Define MassFlowRate units (Mass/Time)
+define_unit_dimension! {
+    dimension MassFlowRate {
+        base_unit: KilogramPerSecond = 1.0,
+        units: {
+            KilogramPerSecond = 1.0,
+            SolarMassPerYear = KG_PER_SOLAR_MASS / SECONDS_PER_YEAR,
+        },
+        symbols: {
+            KilogramPerSecond = "kg/s",
+            SolarMassPerYear = "M☉/yr",
+        }
+    }
+}
+
+// Define AngularMomentum units (Mass×Length²/Time)
+define_unit_dimension! {
+    dimension AngularMomentum {
+        base_unit: KilogramSquareMeterPerSecond = 1.0,
+        units: {
+            KilogramSquareMeterPerSecond = 1.0,
+        },
+        symbols: {
+            KilogramSquareMeterPerSecond = "kg⋅m²/s",
+        }
+    }
+}
+
 // Convenience type aliases for common combinations
 pub type Newton_OLD = Force<Kilogram>; // Actually Force in SI base units  
 pub type Pascal_OLD = Pressure<Kilogram>; // Actually Pressure in SI base units
@@ -330,3 +411,102 @@ pub type Pascal_OLD = Pressure<Kilogram>; // Actually Pressure in SI base units
 pub fn calculate_velocity(distance: Distance<Meter>, time: Time<Second>) -> f64 {
     divide_quantities(distance, time)
 }
+
+// Parsing from strings such as "1.5 AU", "0.7 M☉" or "6 Gyr"
+macro_rules! impl_from_str {
+    ($($dim_name:ident => $base_unit:ident),+ $(,)?) => {
+        $(
+            impl<U> FromStr for $dim_name<U>
+            where
+                $dim_name<U>: ToSI,
+            {
+                type Err = ParseQuantityError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let (value, factor) =
+                        parse_value_and_unit(s, &$dim_name::<$base_unit>::unit_table())?;
+                    // Same unit: the factor ratio is exactly 1, so the value is unchanged
+                    Ok(Self::new(value * (factor / Self::new(1.0).to_si())))
+                }
+            }
+        )+
+    };
+}
+
+impl_from_str! {
+    Distance => Meter,
+    Mass => Kilogram,
+    Time => Second,
+}
+
+// Human-readable formatting in the best-fitting unit
+macro_rules! impl_pretty_string {
+    ($($dim_name:ident => $base_unit:ident),+ $(,)?) => {
+        $(
+            impl<U> $dim_name<U>
+            where
+                $dim_name<U>: ToSI,
+            {
+                /// Formats the quantity in the largest unit whose value is at least 1.
+                pub fn to_pretty_string(&self) -> String {
+                    format_in_best_unit(self.to_si(), &$dim_name::<$base_unit>::unit_table())
+                }
+            }
+        )+
+    };
+}
+
+impl_pretty_string! {
+    Distance => Meter,
+    Mass => Kilogram,
+    Time => Second,
+    Energy => Joule,
+    Power => Watt,
+    Pressure => Pascal,
+}
+
+// Dimensioned products and quotients, e.g. Distance / Time = Velocity.
+// Const generic arithmetic on the exponents is not stable, so every supported
+// combination is listed explicitly; the result is always in SI base units.
+macro_rules! impl_quantity_products {
+    () => {};
+    ($lhs:ident * $rhs:ident => $out:ident<$unit:ident>, $($rest:tt)*) => {
+        impl_quantity_products!(@impl Mul, mul, *, $lhs, $rhs, $out, $unit);
+        impl_quantity_products!($($rest)*);
+    };
+    ($lhs:ident / $rhs:ident => $out:ident<$unit:ident>, $($rest:tt)*) => {
+        impl_quantity_products!(@impl Div, div, /, $lhs, $rhs, $out, $unit);
+        impl_quantity_products!($($rest)*);
+    };
+    (@impl $op_trait:ident, $method:ident, $op:tt, $lhs:ident, $rhs:ident, $out:ident, $unit:ident) => {
+        impl<U1, U2> std::ops::$op_trait<$rhs<U2>> for $lhs<U1>
+        where
+            $lhs<U1>: ToSI,
+            $rhs<U2>: ToSI,
+        {
+            type Output = $out<$unit>;
+
+            fn $method(self, rhs: $rhs<U2>) -> $out<$unit> {
+                $out::<$unit>::new(self.to_si() $op rhs.to_si())
+            }
+        }
+    };
+}
+
+impl_quantity_products! {
+    Distance * Distance => Area<SquareMeter>,
+    Area * Distance => Volume<CubicMeter>,
+    Distance / Time => Velocity<MeterPerSecond>,
+    Distance / Velocity => Time<Second>,
+    Velocity * Time => Distance<Meter>,
+    Velocity / Time => Acceleration<MeterPerSecondSquared>,
+    Acceleration * Time => Velocity<MeterPerSecond>,
+    Mass / Volume => Density<KilogramPerCubicMeter>,
+    Mass / Time => MassFlowRate<KilogramPerSecond>,
+    Mass * Velocity => Momentum<KilogramMeterPerSecond>,
+    Mass * Acceleration => Force<Newton>,
+    Force / Area => Pressure<Pascal>,
+    Force * Distance => Energy<Joule>,
+    Energy / Time => Power<Watt>,
+    Power * Time => Energy<Joule>,
+}
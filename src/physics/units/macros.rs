@@ -25,6 +25,7 @@
 /// - `ToSI` implementation for converting to SI base units
 /// - `FromSI` implementation for converting from SI base units
 /// - Convenience constructor methods on the base unit type
+/// - A `unit_table()` listing every unit's symbol and conversion factor
 ///
 /// # Examples
 ///
@@ -118,6 +119,11 @@ macro_rules! define_unit_dimension {
                     $dim_name::<$unit>::new(value)
                 }
             )+
+
+            /// Every unit of this dimension as `(symbol, factor to the SI base unit)`.
+            pub fn unit_table() -> Vec<(&'static str, f64)> {
+                vec![$((<$unit as UnitSymbol>::symbol(), $conversion)),+]
+            }
         }
     };
 }
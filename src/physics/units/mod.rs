@@ -25,6 +25,23 @@
 //! let invalid = distance + mass; // Compile error!
 //! ```
 //!
+//! Multiplying or dividing quantities yields the derived dimension in SI units:
+//!
+//! ```rust
+//! use star_sim::physics::units::*;
+//!
+//! let distance = Distance::<Kilometer>::new(42.0);
+//! let time = Time::<Hour>::new(1.0);
+//! let speed: Velocity<MeterPerSecond> = distance / time;
+//! let acceleration: Acceleration<MeterPerSecondSquared> = speed / Time::<Second>::new(10.0);
+//! ```
+//!
+//! ```compile_fail
+//! use star_sim::physics::units::*;
+//!
+//! let speed: Velocity<MeterPerSecond> = Mass::<Kilogram>::new(5.0) / Time::<Second>::new(1.0);
+//! ```
+//!
 //! ## 🏭 Macro-Generated Boilerplate
 //!
 //! Adding new units requires minimal code thanks to powerful macros:
@@ -113,6 +130,7 @@ pub mod core;
 pub mod dimensions;
 pub mod macros;
 pub mod prefix;
+pub mod safe_f64;
 
 pub use constants::*;
 pub use core::*;
@@ -0,0 +1,93 @@
+//! Serde helpers for `f64` fields that may hold non-finite values.
+//!
+//! Degenerate physical inputs (zero radius, zero mass, ...) can produce `NaN` or
+//! infinities. Many human-readable formats cannot represent those values (JSON
+//! rejects them outright), so serialization would fail halfway through a system.
+//!
+//! Fields annotated with `#[serde(with = "safe_f64")]` are written as plain numbers
+//! when finite and as a missing value (`None` in RON, `null` in JSON) otherwise.
+//! Missing values deserialize back to `NaN`, so a round trip never panics and the
+//! invalid value is still detectable afterwards. Binary formats are not
+//! human-readable and store the raw `f64` bit pattern unchanged.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use serde::{Deserialize, Serialize};
+//! use star_sim::physics::units::safe_f64;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Score {
+//!     #[serde(with = "safe_f64")]
+//!     value: f64,
+//! }
+//!
+//! let ron = ron::to_string(&Score { value: f64::NAN }).unwrap();
+//! let back: Score = ron::from_str(&ron).unwrap();
+//! assert!(back.value.is_nan());
+//! ```
+
+use serde::Serializer;
+use serde::de::{self, Deserializer, Visitor};
+use std::fmt;
+
+/// Serializes finite values as numbers and non-finite values as a missing value.
+pub fn serialize<S>(value: &f64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if !serializer.is_human_readable() || value.is_finite() {
+        serializer.serialize_f64(*value)
+    } else {
+        serializer.serialize_none()
+    }
+}
+
+/// Deserializes a number, or a missing value as `NaN`.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(SafeF64Visitor)
+    } else {
+        deserializer.deserialize_f64(SafeF64Visitor)
+    }
+}
+
+struct SafeF64Visitor;
+
+impl<'de> Visitor<'de> for SafeF64Visitor {
+    type Value = f64;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a number or a missing value")
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<f64, E> {
+        Ok(value)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<f64, E> {
+        Ok(value as f64)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<f64, E> {
+        Ok(value as f64)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<f64, E> {
+        Ok(f64::NAN)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<f64, E> {
+        Ok(f64::NAN)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<f64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize(deserializer)
+    }
+}
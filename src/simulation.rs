@@ -0,0 +1,143 @@
+// Anbindung der Sternsysteme an die Bevy-ECS-Welt
+use crate::physics::units::*;
+use crate::stellar_objects::{
+    BodyKind, Orbit, PlanetData, SerializableBody, SerializableStellarSystem, StarData,
+};
+
+use bevy::prelude::{
+    App, BuildChildren, Commands, Component, Entity, Name, Plugin, Query, Res, ResMut, Resource,
+    Startup, Transform, Update, Vec3,
+};
+
+/// Ein Stern als Entität; die Daten werden unverändert aus dem System übernommen.
+#[derive(Component, Debug, Clone)]
+pub struct StarEntity(pub StarData);
+
+/// Ein Planet oder Mond als Entität.
+#[derive(Component, Debug, Clone)]
+pub struct PlanetEntity(pub PlanetData);
+
+/// Die Bahn einer Entität um ihre Elternentität.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct OrbitEntity {
+    pub orbit: Orbit,
+    /// Die Gesamtmasse des Zweikörperproblems (Zentralkörper und umlaufender Teilbaum).
+    pub total_mass: Mass<Kilogram>,
+}
+
+/// Das System, das beim Start in die Welt gesetzt wird.
+#[derive(Resource, Debug)]
+pub struct ActiveStarSystem(pub SerializableStellarSystem);
+
+/// Die simulierte Zeit seit dem Start und ihr Verhältnis zur Echtzeit.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct SimulationTime {
+    /// Die seit der Epoche der Bahnelemente vergangene Zeit.
+    pub elapsed: Time<Year>,
+    /// Simulierte Jahre pro Sekunde Echtzeit.
+    pub years_per_second: f64,
+}
+
+impl Default for SimulationTime {
+    fn default() -> Self {
+        Self {
+            elapsed: Time::<Year>::new(0.0),
+            years_per_second: 1.0,
+        }
+    }
+}
+
+impl SimulationTime {
+    /// Eine bei der Epoche beginnende Uhr mit dem angegebenen Zeitraffer.
+    pub fn with_scale(years_per_second: f64) -> Self {
+        Self {
+            years_per_second,
+            ..Default::default()
+        }
+    }
+}
+
+/// Setzt das [`ActiveStarSystem`] als Entitätshierarchie in die Welt und
+/// positioniert alle Körper mit Bahn in jedem Frame aus ihren Bahnelementen
+/// zur aktuellen [`SimulationTime`].
+///
+/// Eine Welteinheit entspricht einer Astronomischen Einheit; Transformationen
+/// sind relativ zur Elternentität, also zum jeweiligen Zentralkörper.
+pub struct StarSystemPlugin;
+
+impl Plugin for StarSystemPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<bevy::time::Time>()
+            .init_resource::<SimulationTime>()
+            .add_systems(Startup, spawn_active_system)
+            .add_systems(Update, advance_orbits);
+    }
+}
+
+/// Erzeugt für jede Wurzel des Systems eine Entitätshierarchie und gibt die
+/// Wurzelentitäten zurück.
+pub fn spawn_star_system(
+    commands: &mut Commands,
+    system: &SerializableStellarSystem,
+) -> Vec<Entity> {
+    system
+        .roots
+        .iter()
+        .map(|root| spawn_body(commands, root, None))
+        .collect()
+}
+
+fn spawn_body(
+    commands: &mut Commands,
+    body: &SerializableBody,
+    orbit: Option<OrbitEntity>,
+) -> Entity {
+    let mut entity = commands.spawn((Name::new(body.name.clone()), Transform::default()));
+    match &body.kind {
+        BodyKind::Star(star) => {
+            entity.insert(StarEntity(star.clone()));
+        }
+        BodyKind::Planet(planet) => {
+            entity.insert(PlanetEntity(planet.clone()));
+        }
+        BodyKind::Barycenter => {}
+    }
+    if let Some(orbit) = orbit {
+        entity.insert(orbit);
+    }
+    let parent = entity.id();
+
+    for satellite in &body.satellites {
+        let orbit = satellite.orbit.map(|orbit| OrbitEntity {
+            orbit,
+            total_mass: body.orbit_total_mass(satellite),
+        });
+        let child = spawn_body(commands, satellite, orbit);
+        commands.entity(parent).add_child(child);
+    }
+    parent
+}
+
+fn spawn_active_system(mut commands: Commands, system: Option<Res<ActiveStarSystem>>) {
+    if let Some(system) = system {
+        spawn_star_system(&mut commands, &system.0);
+    }
+}
+
+/// Schreitet die [`SimulationTime`] um die skalierte Framedauer fort und setzt
+/// jede Entität mit Bahn auf ihren Ort zu diesem Zeitpunkt.
+pub fn advance_orbits(
+    clock: Res<bevy::time::Time>,
+    mut simulation_time: ResMut<SimulationTime>,
+    mut query: Query<(&OrbitEntity, &mut Transform)>,
+) {
+    let step = clock.delta_secs_f64() * simulation_time.years_per_second;
+    simulation_time.elapsed = Time::<Year>::new(simulation_time.elapsed.value() + step);
+
+    let now = simulation_time.elapsed;
+    for (orbit, mut transform) in &mut query {
+        let (position, _) = orbit.orbit.state_vector(now, orbit.total_mass);
+        let [x, y, z] = position.map(|r| r.convert_to::<AstronomicalUnit>().value() as f32);
+        transform.translation = Vec3::new(x, y, z);
+    }
+}
@@ -1,7 +1,20 @@
 // Benötigte Typen aus dem neuen Einheitensystem importieren
+use crate::physics::astrophysics::{calculate_tidal_heating, fluid_roche_limit, transit_depth};
+use crate::physics::constants::{
+    BOLTZMANN_CONSTANT, G, GAUSSIAN_GRAVITATIONAL_CONSTANT, PLANCK_CONSTANT, SPEED_OF_LIGHT,
+    STEFAN_BOLTZMANN,
+};
+use crate::physics::mechanics::dynamic::{
+    PointMass, leapfrog_step, total_angular_momentum, total_energy,
+};
+use crate::physics::mechanics::kinematics::{
+    eccentric_to_mean_anomaly, eccentric_to_true_anomaly, mean_to_eccentric_anomaly,
+    true_to_eccentric_anomaly,
+};
 use crate::physics::units::*;
 
 use bevy::prelude::Component;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::io::Write;
@@ -19,11 +32,17 @@ pub struct ActiveCore(pub bool);
 // 2. Orbitale Mechanik (angepasst an Ihr Einheitensystem)
 //================================================================================
 
+/// Ein kartesischer Ortsvektor (x, y, z) im Bezugssystem des Zentralkörpers.
+pub type Vec3Distance = [Distance<Meter>; 3];
+/// Ein kartesischer Geschwindigkeitsvektor (vx, vy, vz).
+pub type Vec3Velocity = [Velocity<MeterPerSecond>; 3];
+
 #[derive(Component, Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct Orbit {
     /// Die große Halbachse in Astronomischen Einheiten.
     pub semi_major_axis: Distance<AstronomicalUnit>,
     /// Die Exzentrizität (dimensionslos).
+    #[serde(with = "safe_f64")]
     pub eccentricity: f64,
     /// Die Bahnneigung in Radiant.
     pub inclination: Angle<Radian>,
@@ -47,6 +66,493 @@ impl Default for Orbit {
     }
 }
 
+impl Orbit {
+    /// Modifizierter Gezeiten-Qualitätsfaktor Q' für Sterne.
+    const STELLAR_TIDAL_Q: f64 = 1.0e6;
+    /// Apsidenkonstante k₂ eines Hauptreihensterns.
+    const STELLAR_APSIDAL_CONSTANT: f64 = 0.01;
+    /// Lebensdauer eines Systems aus kompakten Überresten, die selbst nicht mehr
+    /// altern, in Gyr; weit jenseits der Hubble-Zeit.
+    const REMNANT_SYSTEM_LIFETIME_GYR: f64 = 1000.0;
+
+    /// Die Umlaufperiode nach dem dritten Keplerschen Gesetz.
+    ///
+    /// `total_mass` ist die Summe der Massen von Zentralkörper und Begleiter.
+    /// Parabel- und Hyperbelbahnen (e ≥ 1) kehren nie zurück, ihre Periode ist
+    /// unendlich.
+    pub fn orbital_period<U>(&self, total_mass: Mass<U>) -> Time<Second>
+    where
+        Mass<U>: ToSI,
+    {
+        if self.eccentricity >= 1.0 {
+            return Time::<Second>::new(f64::INFINITY);
+        }
+        let a = self.semi_major_axis.convert_to::<Meter>().value();
+        let mu = f64::from(G) * total_mass.to_si();
+        Time::<Second>::new(2.0 * std::f64::consts::PI * (a.powi(3) / mu).sqrt())
+    }
+
+    /// Die Restgeschwindigkeit v∞ = √(μ/|a|) einer ungebundenen Bahn weit weg
+    /// vom Zentralkörper.
+    ///
+    /// Parabelbahnen kommen im Unendlichen zur Ruhe; gebundene Bahnen (e < 1)
+    /// haben keine Restgeschwindigkeit und ergeben `None`.
+    pub fn hyperbolic_excess_velocity<U>(
+        &self,
+        total_mass: Mass<U>,
+    ) -> Option<Velocity<MeterPerSecond>>
+    where
+        Mass<U>: ToSI,
+    {
+        if self.eccentricity < 1.0 {
+            return None;
+        }
+        if self.eccentricity == 1.0 {
+            return Some(Velocity::<MeterPerSecond>::new(0.0));
+        }
+        let a = self.semi_major_axis.convert_to::<Meter>().value().abs();
+        let mu = f64::from(G) * total_mass.to_si();
+        Some(Velocity::<MeterPerSecond>::new((mu / a).sqrt()))
+    }
+
+    /// Die synodische Periode zwischen dieser und einer zweiten Bahn um dieselbe
+    /// Zentralmasse: 1 / |1/P₁ − 1/P₂|.
+    ///
+    /// Bei gleichen Perioden stehen die Körper nie erneut in Konjunktion, die
+    /// synodische Periode ist dann unendlich.
+    pub fn synodic_period<U>(&self, other: &Orbit, total_mass: Mass<U>) -> Time<Second>
+    where
+        Mass<U>: ToSI,
+    {
+        let total_mass = total_mass.convert_to::<Kilogram>();
+        let p1 = self.orbital_period::<Kilogram>(total_mass).value();
+        let p2 = other.orbital_period::<Kilogram>(total_mass).value();
+        let beat = (1.0 / p1 - 1.0 / p2).abs();
+        if beat == 0.0 {
+            Time::<Second>::new(f64::INFINITY)
+        } else {
+            Time::<Second>::new(1.0 / beat)
+        }
+    }
+
+    /// Der Tisserand-Parameter bezüglich eines massereichen Begleiters mit der
+    /// großen Halbachse `secondary_semi_major_axis`:
+    /// T = a_s/a + 2·cos(i)·√(a/a_s · (1 − e²)).
+    ///
+    /// Die Neigung wird gegen die Bahnebene des Begleiters gemessen. Ko-orbitale
+    /// Körper liegen bei T ≈ 3, gestreute Körper deutlich darunter.
+    pub fn tisserand_parameter<U>(&self, secondary_semi_major_axis: Distance<U>) -> f64
+    where
+        Distance<U>: ToSI,
+    {
+        let a = self.semi_major_axis.value();
+        let a_s = secondary_semi_major_axis
+            .convert_to::<AstronomicalUnit>()
+            .value();
+        a_s / a
+            + 2.0
+                * self.inclination.value().cos()
+                * (a / a_s * (1.0 - self.eccentricity.powi(2))).sqrt()
+    }
+
+    /// Die mittlere Bewegung n = √(μ/|a|³) in rad/s, für Ellipsen gleich 2π/P.
+    ///
+    /// Hyperbelbahnen haben a < 0 und verwenden |a|. Bei Parabeln (e = 1) ist
+    /// a unendlich; dort wird |a| als Periapsisabstand q gelesen und nach
+    /// Barker n = √(μ/(2q³)) verwendet.
+    pub fn mean_motion<U>(&self, total_mass: Mass<U>) -> f64
+    where
+        Mass<U>: ToSI,
+    {
+        let a = self.semi_major_axis.convert_to::<Meter>().value().abs();
+        let mu = f64::from(G) * total_mass.to_si();
+        if self.eccentricity == 1.0 {
+            (mu / (2.0 * a.powi(3))).sqrt()
+        } else {
+            (mu / a.powi(3)).sqrt()
+        }
+    }
+
+    /// Der Bahnparameter p = |a|·|1 − e²| in Metern; bei Parabeln p = 2q.
+    fn semi_latus_rectum(&self) -> f64 {
+        let a = self.semi_major_axis.convert_to::<Meter>().value().abs();
+        if self.eccentricity == 1.0 {
+            2.0 * a
+        } else {
+            a * (1.0 - self.eccentricity.powi(2)).abs()
+        }
+    }
+
+    /// Die wahre Anomalie nach der Zeit `time` seit der Epoche.
+    ///
+    /// Die mittlere Anomalie M = M₀ + n·t wird über die Kepler-Gleichung in die
+    /// exzentrische und daraus in die wahre Anomalie umgerechnet; für e ≥ 1 über
+    /// die hyperbolische bzw. parabolische Form
+    /// ([`mean_to_eccentric_anomaly`]).
+    pub fn true_anomaly_at_time<T, U>(&self, time: Time<T>, total_mass: Mass<U>) -> Angle<Radian>
+    where
+        Time<T>: ToSI,
+        Mass<U>: ToSI,
+    {
+        let mean_anomaly =
+            self.mean_anomaly_at_epoch.value() + self.mean_motion(total_mass) * time.to_si();
+        let eccentric_anomaly = mean_to_eccentric_anomaly(mean_anomaly, self.eccentricity);
+        Angle::<Radian>::new(eccentric_to_true_anomaly(
+            eccentric_anomaly,
+            self.eccentricity,
+        ))
+    }
+
+    /// Orts- und Geschwindigkeitsvektor nach der Zeit `time` seit der Epoche.
+    ///
+    /// Die Vektoren werden im perifokalen System berechnet und mit der Rotation
+    /// R₃(−Ω)·R₁(−i)·R₃(−ω) in das Bezugssystem der Referenzebene gedreht.
+    pub fn state_vector<T, U>(
+        &self,
+        time: Time<T>,
+        total_mass: Mass<U>,
+    ) -> (Vec3Distance, Vec3Velocity)
+    where
+        Time<T>: ToSI,
+        Mass<U>: ToSI,
+    {
+        let total_mass = total_mass.convert_to::<Kilogram>();
+        let nu = self
+            .true_anomaly_at_time::<T, Kilogram>(time, total_mass)
+            .value();
+        let mu = f64::from(G) * total_mass.value();
+        let e = self.eccentricity;
+        let p = self.semi_latus_rectum();
+
+        let r = p / (1.0 + e * nu.cos());
+        let position = [r * nu.cos(), r * nu.sin()];
+        let speed = (mu / p).sqrt();
+        let velocity = [-speed * nu.sin(), speed * (e + nu.cos())];
+
+        let (sin_node, cos_node) = self.longitude_of_ascending_node.value().sin_cos();
+        let (sin_i, cos_i) = self.inclination.value().sin_cos();
+        let (sin_w, cos_w) = self.argument_of_periapsis.value().sin_cos();
+        // Spalten: Einheitsvektoren P (Richtung Periapsis) und Q im Bezugssystem
+        let rotation = [
+            [
+                cos_node * cos_w - sin_node * sin_w * cos_i,
+                -cos_node * sin_w - sin_node * cos_w * cos_i,
+            ],
+            [
+                sin_node * cos_w + cos_node * sin_w * cos_i,
+                -sin_node * sin_w + cos_node * cos_w * cos_i,
+            ],
+            [sin_w * sin_i, cos_w * sin_i],
+        ];
+        let rotate = |v: [f64; 2]| rotation.map(|row| row[0] * v[0] + row[1] * v[1]);
+
+        (
+            rotate(position).map(Distance::<Meter>::new),
+            rotate(velocity).map(Velocity::<MeterPerSecond>::new),
+        )
+    }
+
+    /// Die Bahnelemente zu einem Orts- und Geschwindigkeitsvektor; die Epoche ist
+    /// der Zeitpunkt des Zustandsvektors.
+    ///
+    /// Entartete Fälle erhalten konventionelle Nullwerte: Bei Kreisbahnen ist das
+    /// Argument der Periapsis 0 und die Anomalie wird vom aufsteigenden Knoten
+    /// gezählt, bei äquatorialen Bahnen ist die Knotenlänge 0 und Winkel werden
+    /// von der x-Achse gezählt.
+    pub fn from_state_vector<U>(
+        position: Vec3Distance,
+        velocity: Vec3Velocity,
+        total_mass: Mass<U>,
+    ) -> Orbit
+    where
+        Mass<U>: ToSI,
+    {
+        const DEGENERATE: f64 = 1e-11;
+        let tau = 2.0 * std::f64::consts::PI;
+        let dot = |a: [f64; 3], b: [f64; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+        let norm = |a: [f64; 3]| dot(a, a).sqrt();
+        // Winkel zwischen zwei Vektoren, in [π, 2π) gespiegelt, wenn `flip` gilt
+        let angle = |a: [f64; 3], b: [f64; 3], flip: bool| {
+            let cos = (dot(a, b) / (norm(a) * norm(b))).clamp(-1.0, 1.0);
+            if flip { tau - cos.acos() } else { cos.acos() }
+        };
+
+        let mu = f64::from(G) * total_mass.to_si();
+        let r = position.map(|x| x.value());
+        let v = velocity.map(|x| x.value());
+        let (r_len, v_len) = (norm(r), norm(v));
+        let radial_velocity = dot(r, v);
+
+        let h = [
+            r[1] * v[2] - r[2] * v[1],
+            r[2] * v[0] - r[0] * v[2],
+            r[0] * v[1] - r[1] * v[0],
+        ];
+        let h_len = norm(h);
+        let node = [-h[1], h[0], 0.0];
+        let node_len = norm(node);
+        let e_vec =
+            [0, 1, 2].map(|k| ((v_len * v_len - mu / r_len) * r[k] - radial_velocity * v[k]) / mu);
+        let e = norm(e_vec);
+
+        let inclination = (h[2] / h_len).clamp(-1.0, 1.0).acos();
+        let equatorial = node_len <= DEGENERATE * h_len;
+        let circular = e <= DEGENERATE.sqrt();
+        let longitude_of_ascending_node = if equatorial {
+            0.0
+        } else {
+            node[1].atan2(node[0]).rem_euclid(tau)
+        };
+        // Bei äquatorialen Bahnen zeigt die Knotenlinie konventionell entlang x
+        let reference = if equatorial { [1.0, 0.0, 0.0] } else { node };
+        let retrograde = h[2] < 0.0;
+        let argument_of_periapsis = if circular {
+            0.0
+        } else if equatorial {
+            angle(reference, e_vec, (e_vec[1] < 0.0) != retrograde)
+        } else {
+            angle(reference, e_vec, e_vec[2] < 0.0)
+        };
+        let true_anomaly = if !circular {
+            angle(e_vec, r, radial_velocity < 0.0)
+        } else if equatorial {
+            angle(reference, r, (r[1] < 0.0) != retrograde)
+        } else {
+            angle(reference, r, r[2] < 0.0)
+        };
+
+        let eccentricity = if circular { 0.0 } else { e };
+        let eccentric_anomaly = true_to_eccentric_anomaly(true_anomaly, eccentricity);
+        let mean_anomaly = eccentric_to_mean_anomaly(eccentric_anomaly, eccentricity);
+        let semi_major_axis = 1.0 / (2.0 / r_len - v_len * v_len / mu);
+
+        Orbit {
+            semi_major_axis: Distance::<Meter>::new(semi_major_axis)
+                .convert_to::<AstronomicalUnit>(),
+            eccentricity,
+            inclination: Angle::<Radian>::new(inclination),
+            longitude_of_ascending_node: Angle::<Radian>::new(longitude_of_ascending_node),
+            argument_of_periapsis: Angle::<Radian>::new(argument_of_periapsis),
+            // Die hyperbolische mittlere Anomalie ist nicht periodisch
+            mean_anomaly_at_epoch: Angle::<Radian>::new(if eccentricity < 1.0 {
+                mean_anomaly.rem_euclid(tau)
+            } else {
+                mean_anomaly
+            }),
+        }
+    }
+
+    /// Die Bahngeschwindigkeit im Abstand `r` nach der Vis-Viva-Gleichung
+    /// v² = μ · (2/r − 1/a).
+    fn vis_viva<U>(&self, total_mass: Mass<U>, r: Distance<Meter>) -> Velocity<MeterPerSecond>
+    where
+        Mass<U>: ToSI,
+    {
+        let a = self.semi_major_axis.convert_to::<Meter>().value();
+        let mu = f64::from(G) * total_mass.to_si();
+        Velocity::<MeterPerSecond>::new((mu * (2.0 / r.value() - 1.0 / a)).sqrt())
+    }
+
+    /// Die Kreisbahngeschwindigkeit v = k · √(M/a) in AE pro Jahr.
+    ///
+    /// Rechnet mit der Gaußschen Gravitationskonstante direkt in astronomischen
+    /// Einheiten (M in M☉, a in AE); für die Erde ergibt sich 2π AE/Jahr.
+    pub fn circular_velocity<U>(&self, total_mass: Mass<U>) -> Velocity<AstronomicalUnitPerYear>
+    where
+        Mass<U>: ToSI,
+    {
+        self.velocity_at_distance(self.semi_major_axis, total_mass)
+    }
+
+    /// Die Bahngeschwindigkeit im Abstand `distance` in AE pro Jahr, nach der
+    /// Vis-Viva-Gleichung v² = k² · M · (2/r − 1/a) in astronomischen Einheiten.
+    pub fn velocity_at_distance<D, U>(
+        &self,
+        distance: Distance<D>,
+        total_mass: Mass<U>,
+    ) -> Velocity<AstronomicalUnitPerYear>
+    where
+        Distance<D>: ToSI,
+        Mass<U>: ToSI,
+    {
+        let k_per_year =
+            f64::from(GAUSSIAN_GRAVITATIONAL_CONSTANT) * SECONDS_PER_YEAR / SECONDS_PER_DAY;
+        let mass = total_mass.convert_to::<SolarMass>().value();
+        let r = distance.convert_to::<AstronomicalUnit>().value();
+        let a = self.semi_major_axis.value();
+        Velocity::<AstronomicalUnitPerYear>::new(k_per_year * (mass * (2.0 / r - 1.0 / a)).sqrt())
+    }
+
+    /// Der kleinste Abstand zum Zentralkörper, r_p = a · (1 − e).
+    pub fn periapsis_distance(&self) -> Distance<Meter> {
+        self.semi_major_axis.convert_to::<Meter>() * (1.0 - self.eccentricity)
+    }
+
+    /// Der größte Abstand zum Zentralkörper, r_a = a · (1 + e).
+    pub fn apoapsis_distance(&self) -> Distance<Meter> {
+        self.semi_major_axis.convert_to::<Meter>() * (1.0 + self.eccentricity)
+    }
+
+    /// Die Bahngeschwindigkeit im Perizentrum.
+    pub fn velocity_at_periapsis<U>(&self, total_mass: Mass<U>) -> Velocity<MeterPerSecond>
+    where
+        Mass<U>: ToSI,
+    {
+        self.vis_viva(total_mass, self.periapsis_distance())
+    }
+
+    /// Die Bahngeschwindigkeit im Apozentrum.
+    pub fn velocity_at_apoapsis<U>(&self, total_mass: Mass<U>) -> Velocity<MeterPerSecond>
+    where
+        Mass<U>: ToSI,
+    {
+        self.vis_viva(total_mass, self.apoapsis_distance())
+    }
+
+    /// Prüft die Drehimpulserhaltung r_p · v_p = r_a · v_a zwischen den unabhängig
+    /// per Vis-Viva berechneten Geschwindigkeiten in Peri- und Apozentrum.
+    pub fn verify_angular_momentum<U>(&self, total_mass: Mass<U>) -> bool
+    where
+        Mass<U>: ToSI,
+    {
+        let total_mass = total_mass.convert_to::<Kilogram>();
+        let h_periapsis = self.periapsis_distance().value()
+            * self.velocity_at_periapsis::<Kilogram>(total_mass).value();
+        let h_apoapsis = self.apoapsis_distance().value()
+            * self.velocity_at_apoapsis::<Kilogram>(total_mass).value();
+        debug_assert!(h_periapsis.is_finite() && h_apoapsis.is_finite());
+        (h_periapsis - h_apoapsis).abs() <= 1e-9 * h_periapsis.abs().max(h_apoapsis.abs())
+    }
+
+    /// Die Zeitskala, auf der Gezeitenreibung im Hauptstern die Bahn zirkularisiert.
+    ///
+    /// τ = 4/63 · Q' · (M/m) · (a/R)⁵ / n, mit M und R des Hauptsterns und m des Begleiters.
+    pub fn circularization_timescale(
+        &self,
+        primary: &StarData,
+        secondary: &StarData,
+    ) -> Time<Second> {
+        let a = self.semi_major_axis.convert_to::<Meter>().value();
+        let radius = primary.radius.convert_to::<Meter>().value();
+        let mass_ratio = primary.mass.value() / secondary.mass.value();
+        let n = self.mean_motion(primary.mass + secondary.mass);
+
+        Time::<Second>::new(
+            4.0 / 63.0 * Self::STELLAR_TIDAL_Q * mass_ratio * (a / radius).powi(5) / n,
+        )
+    }
+
+    /// Die Zeit bis zur Verschmelzung durch Abstrahlung von Gravitationswellen.
+    ///
+    /// Für Kreisbahnen nach Peters (1964) T_c = 5/256 · c⁵a⁴ / (G³ m₁m₂(m₁+m₂)),
+    /// für exzentrische Bahnen mit der Näherung von Mandel (2021)
+    /// T ≈ T_c · (1 + 0.27e¹⁰ + 0.33e²⁰ + 0.2e¹⁰⁰⁰) · (1 − e²)^(7/2).
+    pub fn gravitational_wave_inspiral_time(
+        &self,
+        primary: &StarData,
+        secondary: &StarData,
+    ) -> Time<Gigayear> {
+        let g = f64::from(G);
+        let c = f64::from(SPEED_OF_LIGHT);
+        let a = self.semi_major_axis.convert_to::<Meter>().value();
+        let e = self.eccentricity;
+        let m1 = primary.mass.convert_to::<Kilogram>().value();
+        let m2 = secondary.mass.convert_to::<Kilogram>().value();
+
+        let circular = 5.0 / 256.0 * c.powi(5) * a.powi(4) / (g.powi(3) * m1 * m2 * (m1 + m2));
+        let eccentric = circular
+            * (1.0 + 0.27 * e.powi(10) + 0.33 * e.powi(20) + 0.2 * e.powi(1000))
+            * (1.0 - e * e).powf(3.5);
+        Time::<Second>::new(eccentric).convert_to::<Gigayear>()
+    }
+
+    /// Die Verschmelzungszeit nach [`Self::gravitational_wave_inspiral_time`],
+    /// falls das Paar verschmilzt, bevor einer der Hauptreihensterne nach dem
+    /// Systemalter `age` seine Hauptreihe verlässt.
+    ///
+    /// Kompakte Überreste (Spektraltyp D bzw. Leuchtkraftklasse VII, also Weiße
+    /// Zwerge und Neutronensterne) entwickeln sich nicht weiter und begrenzen die
+    /// Zeit nicht; ein Paar aus zwei Überresten muss innerhalb von
+    /// [`Self::REMNANT_SYSTEM_LIFETIME_GYR`] verschmelzen.
+    fn merger_time(
+        &self,
+        primary: &StarData,
+        secondary: &StarData,
+        age: Time<Gigayear>,
+    ) -> Option<Time<Gigayear>> {
+        let remaining = [primary, secondary]
+            .into_iter()
+            .filter(|star| !star.is_white_dwarf())
+            .map(|star| star.main_sequence_lifetime().value() - age.value())
+            .fold(Self::REMNANT_SYSTEM_LIFETIME_GYR - age.value(), f64::min);
+        let inspiral = self.gravitational_wave_inspiral_time(primary, secondary);
+        (inspiral.value() < remaining).then_some(inspiral)
+    }
+
+    /// Die Drehung der Apsidenlinie in Grad pro Jahr.
+    ///
+    /// Der relativistische Anteil beträgt 6πGM / (a(1 − e²)c²) pro Umlauf. Mit
+    /// `include_tides` kommt die Gezeitenverformung beider Sterne hinzu
+    /// (Sterne 1939): 15 · f(e) · Σ k₂ · (R/a)⁵ · m_Begleiter/m pro Radiant
+    /// Bahnbewegung, mit f(e) = (1 + 3/2·e² + 1/8·e⁴) / (1 − e²)⁵.
+    pub fn apsidal_precession_rate(
+        &self,
+        primary: &StarData,
+        secondary: &StarData,
+        include_tides: bool,
+    ) -> f64 {
+        let c = f64::from(SPEED_OF_LIGHT);
+        let total_mass = primary.mass + secondary.mass;
+        let a = self.semi_major_axis.convert_to::<Meter>().value();
+        let e = self.eccentricity;
+        let n = self.mean_motion(total_mass);
+
+        let relativistic_per_orbit = 6.0 * std::f64::consts::PI * f64::from(G) * total_mass.to_si()
+            / (a * (1.0 - e * e) * c * c);
+        let mut rate = relativistic_per_orbit * n / (2.0 * std::f64::consts::PI);
+
+        if include_tides {
+            let f = (1.0 + 1.5 * e * e + 0.125 * e.powi(4)) / (1.0 - e * e).powi(5);
+            let distortion = |star: &StarData, companion: &StarData| {
+                Self::STELLAR_APSIDAL_CONSTANT
+                    * (star.radius.convert_to::<Meter>().value() / a).powi(5)
+                    * companion.mass.value()
+                    / star.mass.value()
+            };
+            rate +=
+                15.0 * f * (distortion(primary, secondary) + distortion(secondary, primary)) * n;
+        }
+        (rate * SECONDS_PER_YEAR).to_degrees()
+    }
+
+    /// Die Bahn nach `elapsed` Zeit Gezeitenentwicklung; die Exzentrizität fällt
+    /// exponentiell mit der Zirkularisierungszeitskala ab.
+    pub fn tidally_evolved<U>(
+        &self,
+        primary: &StarData,
+        secondary: &StarData,
+        elapsed: Time<U>,
+    ) -> Orbit
+    where
+        Time<U>: ToSI,
+    {
+        let tau = self.circularization_timescale(primary, secondary).value();
+        Orbit {
+            eccentricity: self.eccentricity * (-elapsed.to_si() / tau).exp(),
+            ..*self
+        }
+    }
+
+    /// Der Winkel zwischen den Bahnebenen zweier Bahnen (gegenseitige Inklination).
+    pub fn mutual_inclination(&self, other: &Orbit) -> Angle<Radian> {
+        let (i1, i2) = (self.inclination.value(), other.inclination.value());
+        let delta_node =
+            self.longitude_of_ascending_node.value() - other.longitude_of_ascending_node.value();
+        let cos_mutual = i1.cos() * i2.cos() + i1.sin() * i2.sin() * delta_node.cos();
+        Angle::<Radian>::new(cos_mutual.clamp(-1.0, 1.0).acos())
+    }
+}
+
 //================================================================================
 // 3. Klassifizierung von Himmelskörpern (bleibt größtenteils gleich)
 //================================================================================
@@ -64,6 +570,84 @@ pub enum SpectralType {
     T,
     Y,
     D,
+    /// Wolf-Rayet-Stern: massereicher, entwickelter Stern ohne Wasserstoffhülle.
+    WR,
+}
+
+impl std::fmt::Display for SpectralType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpectralType::O(n) => write!(f, "O{}", n),
+            SpectralType::B(n) => write!(f, "B{}", n),
+            SpectralType::A(n) => write!(f, "A{}", n),
+            SpectralType::F(n) => write!(f, "F{}", n),
+            SpectralType::G(n) => write!(f, "G{}", n),
+            SpectralType::K(n) => write!(f, "K{}", n),
+            SpectralType::M(n) => write!(f, "M{}", n),
+            SpectralType::L => write!(f, "L"),
+            SpectralType::T => write!(f, "T"),
+            SpectralType::Y => write!(f, "Y"),
+            SpectralType::D => write!(f, "D"),
+            SpectralType::WR => write!(f, "WR"),
+        }
+    }
+}
+
+/// Fehler beim Lesen eines Spektraltyps wie "K5".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseSpectralTypeError {
+    /// Die Eingabe beginnt mit keiner bekannten Spektralklasse.
+    UnknownClass(String),
+    /// Die Unterklasse fehlt oder liegt nicht zwischen 0 und 9.
+    InvalidSubclass(String),
+}
+
+impl std::fmt::Display for ParseSpectralTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseSpectralTypeError::UnknownClass(input) => {
+                write!(f, "unbekannte Spektralklasse in '{}'", input)
+            }
+            ParseSpectralTypeError::InvalidSubclass(input) => {
+                write!(f, "ungültige Unterklasse in '{}' (erwartet 0-9)", input)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseSpectralTypeError {}
+
+impl std::str::FromStr for SpectralType {
+    type Err = ParseSpectralTypeError;
+
+    /// Liest die Notation von [`Display`](std::fmt::Display), z.B. "K5", "T" oder "WR".
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim();
+        match input {
+            "L" => return Ok(SpectralType::L),
+            "T" => return Ok(SpectralType::T),
+            "Y" => return Ok(SpectralType::Y),
+            "D" => return Ok(SpectralType::D),
+            "WR" => return Ok(SpectralType::WR),
+            _ => {}
+        }
+
+        let mut chars = input.chars();
+        let class: fn(u8) -> SpectralType = match chars.next() {
+            Some('O') => SpectralType::O,
+            Some('B') => SpectralType::B,
+            Some('A') => SpectralType::A,
+            Some('F') => SpectralType::F,
+            Some('G') => SpectralType::G,
+            Some('K') => SpectralType::K,
+            Some('M') => SpectralType::M,
+            _ => return Err(ParseSpectralTypeError::UnknownClass(input.to_string())),
+        };
+        match chars.as_str().parse::<u8>() {
+            Ok(subclass) if subclass <= 9 && chars.as_str().len() == 1 => Ok(class(subclass)),
+            _ => Err(ParseSpectralTypeError::InvalidSubclass(input.to_string())),
+        }
+    }
 }
 
 #[derive(Component, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -78,6 +662,22 @@ pub enum LuminosityClass {
     VII,
 }
 
+impl std::fmt::Display for LuminosityClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            LuminosityClass::Ia => "Ia",
+            LuminosityClass::Ib => "Ib",
+            LuminosityClass::II => "II",
+            LuminosityClass::III => "III",
+            LuminosityClass::IV => "IV",
+            LuminosityClass::V => "V",
+            LuminosityClass::VI => "VI",
+            LuminosityClass::VII => "VII",
+        };
+        write!(f, "{}", label)
+    }
+}
+
 #[derive(Component, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BodyType {
     Rocky,
@@ -90,11 +690,31 @@ pub enum BodyType {
     Cthonian,
 }
 
+impl BodyType {
+    /// Typische Bond-Albedo und Treibhausfaktor (T_Oberfläche / T_Gleichgewicht).
+    ///
+    /// Felsplaneten sind wie die Erde (0.3, 255 K → 288 K) parametrisiert,
+    /// Eiswelten reflektieren stark und haben kaum Treibhauswirkung, dichte
+    /// Hüllen von Mini-Neptunen wärmen deutlich.
+    pub fn surface_model(&self) -> (f64, f64) {
+        match self {
+            BodyType::Rocky => (0.3, 1.13),
+            BodyType::SuperEarth => (0.3, 1.2),
+            BodyType::WaterWorld => (0.25, 1.15),
+            BodyType::IceWorld => (0.6, 1.02),
+            BodyType::MiniNeptune => (0.35, 1.5),
+            BodyType::IceGiant => (0.3, 1.0),
+            BodyType::GasGiant => (0.34, 1.0),
+            BodyType::Cthonian => (0.1, 1.0),
+        }
+    }
+}
+
 //================================================================================
 // 4. Serializable Strukturen für die RON-Ausgabe (angepasst)
 //================================================================================
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StarData {
     pub mass: Mass<SolarMass>,
     pub radius: Distance<SunRadius>,
@@ -104,12 +724,1241 @@ pub struct StarData {
     pub luminosity_class: LuminosityClass,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Ein ringförmiger Bereich um einen Stern, in dem flüssiges Wasser möglich ist.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct HabitableZone {
+    pub inner_edge: Distance<AstronomicalUnit>,
+    pub outer_edge: Distance<AstronomicalUnit>,
+}
+
+impl HabitableZone {
+    /// Die radiale Breite der Zone.
+    pub fn width(&self) -> Distance<AstronomicalUnit> {
+        self.outer_edge - self.inner_edge
+    }
+
+    /// Ob die Zone zu einem Punkt zusammengefallen ist.
+    pub fn is_empty(&self) -> bool {
+        self.outer_edge.value() <= self.inner_edge.value()
+    }
+
+    /// Ob der Abstand `distance` in der Zone liegt.
+    pub fn contains<D>(&self, distance: Distance<D>) -> bool
+    where
+        Distance<D>: ToSI,
+    {
+        let au = distance.convert_to::<AstronomicalUnit>().value();
+        au >= self.inner_edge.value() && au <= self.outer_edge.value()
+    }
+}
+
+/// Die magnetische Aktivität eines Sterns, abgeleitet aus Rotation und Alter.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct StellarActivity {
+    /// Die Rotationsperiode an der Oberfläche.
+    pub rotation_period: Time<Day>,
+    /// Verhältnis von Rotationsperiode zu konvektiver Umschlagzeit.
+    pub rossby_number: f64,
+    /// Der Anteil der Röntgenleuchtkraft an der bolometrischen Leuchtkraft.
+    pub xray_luminosity_fraction: f64,
+    /// Die Anzahl energiereicher Flares (E > 10³² erg) pro Tag.
+    pub flare_frequency: f64,
+}
+
+/// Ein einzelner Flare in einer simulierten Zeitreihe.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct FlareEvent {
+    /// Der Zeitpunkt relativ zum Beginn der Simulation.
+    pub time_offset: Time<Day>,
+    /// Die freigesetzte Energie.
+    pub energy: Energy<Erg>,
+    /// Die Dauer des Flares.
+    pub duration: Time<Minute>,
+}
+
+/// Die Massenverlustraten einer Planetenatmosphäre, getrennt nach Mechanismus.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct AtmosphericEscape {
+    /// Thermische Jeans-Flucht schwerer Teilchen (atomarer Sauerstoff).
+    pub jeans: MassFlowRate<KilogramPerSecond>,
+    /// XUV-getriebene hydrodynamische Flucht von Wasserstoff.
+    pub hydrodynamic: MassFlowRate<KilogramPerSecond>,
+}
+
+impl AtmosphericEscape {
+    /// Der gesamte Massenverlust beider Mechanismen.
+    pub fn total(&self) -> MassFlowRate<KilogramPerSecond> {
+        self.jeans + self.hydrodynamic
+    }
+}
+
+/// Die Energiebilanz eines Mondes um einen Riesenplaneten.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MoonHabitability {
+    /// Der mittlere Sternfluss am Ort des Planeten in W/m².
+    pub stellar_flux: f64,
+    /// Reflektiertes Licht und Wärmestrahlung des Planeten in W/m².
+    pub planetary_flux: f64,
+    /// Der Gezeitenwärmefluss durch die Mondoberfläche in W/m².
+    pub tidal_heating_flux: f64,
+    /// Der Anteil der Umlaufbahn im Schatten des Planeten.
+    pub eclipse_fraction: f64,
+    /// Die Gleichgewichtstemperatur aller Wärmequellen zusammen.
+    pub equilibrium_temperature: Temperature<Kelvin>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlanetData {
     pub body_type: BodyType,
     pub mass: Mass<EarthMass>,
     pub radius: Distance<EarthRadius>,
     pub active_core: ActiveCore,
+    /// Die Achsneigung gegenüber der Bahnebene.
+    #[serde(default)]
+    pub obliquity: Angle<Radian>,
+    /// Die siderische Rotationsperiode.
+    #[serde(default = "PlanetData::default_rotation_period")]
+    pub rotation_period: Time<Hour>,
+    /// Ein optionales Ringsystem in der Äquatorebene.
+    #[serde(default)]
+    pub rings: Option<RingSystem>,
+}
+
+/// Ein Ringsystem in der Äquatorebene eines Planeten, gemessen vom Planetenzentrum.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RingSystem {
+    pub inner_radius: Distance<Kilometer>,
+    pub outer_radius: Distance<Kilometer>,
+}
+
+impl RingSystem {
+    /// Ringe, die knapp über der Wolkenobergrenze beginnen und bis zur
+    /// Roche-Grenze für Eispartikel reichen.
+    pub fn within_roche_limit(planet: &PlanetData) -> Self {
+        let radius = planet.radius.convert_to::<Meter>().value();
+        Self {
+            inner_radius: Distance::<Meter>::new(1.2 * radius).convert_to::<Kilometer>(),
+            outer_radius: fluid_roche_limit(
+                planet.mass,
+                planet.radius,
+                PlanetData::RING_PARTICLE_DENSITY,
+            )
+            .convert_to::<Kilometer>(),
+        }
+    }
+}
+
+/// Mittlere Dichte einer Kugel in kg/m³.
+fn mean_density_of(mass_kg: f64, radius_m: f64) -> f64 {
+    mass_kg / (4.0 / 3.0 * std::f64::consts::PI * radius_m.powi(3))
+}
+
+/// Oberflächenschwerebeschleunigung einer Kugel (g = G·M/R²).
+fn surface_gravity_of(mass_kg: f64, radius_m: f64) -> Acceleration<MeterPerSecondSquared> {
+    Acceleration::<MeterPerSecondSquared>::new(f64::from(G) * mass_kg / radius_m.powi(2))
+}
+
+impl StarData {
+    /// Maximale Anfangsmasse (M☉), die als Weißer Zwerg endet.
+    const MAX_WHITE_DWARF_PROGENITOR_MASS: f64 = 8.0;
+    /// Leuchtkraft (L☉) eines 0.6 M☉ Weißen Zwergs nach 1 Gyr Abkühlung.
+    const MESTEL_LUMINOSITY_1_GYR: f64 = 1.0e-3;
+    /// Radius (R☉) eines 0.6 M☉ Weißen Zwergs.
+    const WHITE_DWARF_RADIUS_0_6: f64 = 0.0126;
+    /// Effektivtemperatur der Sonne in K.
+    const SOLAR_TEMPERATURE: f64 = 5778.0;
+    /// Absolute bolometrische Helligkeit der Sonne.
+    const SOLAR_BOLOMETRIC_MAGNITUDE: f64 = 4.74;
+    /// Ab dieser Masse (M☉) verkürzt der Sternwind die Hauptreihen-Lebensdauer.
+    const MIN_WIND_LIFETIME_MASS: f64 = 15.0;
+    /// Effektivtemperatur (K), ab der der Wind linienstrahlungsgetrieben ist.
+    const MIN_LINE_DRIVEN_WIND_TEMPERATURE: f64 = 25_000.0;
+    /// Repräsentative ZAMS-Massen (M☉) der Unterklasse 0 von O bis M und der
+    /// Braunen Zwerge L, T und Y; Unterklassen werden dazwischen interpoliert.
+    const SPECTRAL_CLASS_MASSES: [f64; 10] =
+        [120.0, 17.5, 2.9, 1.6, 1.05, 0.88, 0.57, 0.075, 0.05, 0.02];
+    /// Typische heutige Masse (M☉), Radius (R☉) und Leuchtkraft (L☉) eines
+    /// Wolf-Rayet-Sterns der galaktischen WN/WC-Population.
+    const WOLF_RAYET_MASS: f64 = 15.0;
+    const WOLF_RAYET_RADIUS: f64 = 1.5;
+    const WOLF_RAYET_LUMINOSITY: f64 = 2.0e5;
+
+    /// Ein Stern aus beobachtetem Spektraltyp und Leuchtkraftklasse im Alter `age`.
+    ///
+    /// Der Spektraltyp wird über eine Tabelle einer repräsentativen ZAMS-Masse
+    /// zugeordnet. Leuchtkraft (L ∝ M⁴ bzw. M^3.5) und Radius (R ∝ M^0.8 bzw.
+    /// M^0.57) folgen den Hauptreihen-Beziehungen, die Leuchtkraft wird mit
+    /// `luminosity_at_age` auf das Alter entwickelt und die Temperatur aus dem
+    /// Stefan-Boltzmann-Gesetz bestimmt. Die Leuchtkraftklasse wird übernommen.
+    /// Weiße Zwerge (`D`) haben 0.6 M☉ und kühlen seit `age` nach Mestel ab,
+    /// Wolf-Rayet-Sterne (`WR`) erhalten typische Werte ihrer kurzen Endphase.
+    pub fn from_spectral_type<U>(
+        spectral_type: SpectralType,
+        luminosity_class: LuminosityClass,
+        age: Time<U>,
+    ) -> Self
+    where
+        Time<U>: ToSI,
+    {
+        let age = age.convert_to::<Gigayear>();
+        let (class, subclass) = match spectral_type {
+            SpectralType::O(n) => (0, n),
+            SpectralType::B(n) => (1, n),
+            SpectralType::A(n) => (2, n),
+            SpectralType::F(n) => (3, n),
+            SpectralType::G(n) => (4, n),
+            SpectralType::K(n) => (5, n),
+            SpectralType::M(n) => (6, n),
+            SpectralType::L => (7, 0),
+            SpectralType::T => (8, 0),
+            SpectralType::Y => (9, 0),
+            SpectralType::D => {
+                let luminosity = Self::MESTEL_LUMINOSITY_1_GYR * age.value().max(1e-3).powf(-1.4);
+                let radius = Self::WHITE_DWARF_RADIUS_0_6;
+                return StarData {
+                    mass: Mass::<SolarMass>::new(0.6),
+                    radius: Distance::<SunRadius>::new(radius),
+                    temperature: Temperature::<Kelvin>::new(
+                        Self::SOLAR_TEMPERATURE * (luminosity / radius.powi(2)).powf(0.25),
+                    ),
+                    luminosity: Power::<SolarLuminosity>::new(luminosity),
+                    spectral_type,
+                    luminosity_class,
+                };
+            }
+            SpectralType::WR => {
+                let luminosity = Self::WOLF_RAYET_LUMINOSITY;
+                let radius = Self::WOLF_RAYET_RADIUS;
+                return StarData {
+                    mass: Mass::<SolarMass>::new(Self::WOLF_RAYET_MASS),
+                    radius: Distance::<SunRadius>::new(radius),
+                    temperature: Temperature::<Kelvin>::new(
+                        Self::SOLAR_TEMPERATURE * (luminosity / radius.powi(2)).powf(0.25),
+                    ),
+                    luminosity: Power::<SolarLuminosity>::new(luminosity),
+                    spectral_type,
+                    luminosity_class,
+                };
+            }
+        };
+
+        let masses = Self::SPECTRAL_CLASS_MASSES;
+        let next = masses[(class + 1).min(masses.len() - 1)];
+        let mass = masses[class] + (next - masses[class]) * f64::from(subclass.min(9)) / 10.0;
+
+        let luminosity = match mass {
+            m if m < 0.43 => 0.23 * m.powf(2.3),
+            m if m < 2.0 => m.powi(4),
+            m if m < 55.0 => 1.4 * m.powf(3.5),
+            m => 32_000.0 * m,
+        };
+        let radius = if mass < 1.0 {
+            mass.powf(0.8)
+        } else {
+            mass.powf(0.57)
+        };
+        let mut star = StarData {
+            mass: Mass::<SolarMass>::new(mass),
+            radius: Distance::<SunRadius>::new(radius),
+            temperature: Temperature::<Kelvin>::new(Self::SOLAR_TEMPERATURE),
+            luminosity: Power::<SolarLuminosity>::new(luminosity),
+            spectral_type,
+            luminosity_class,
+        };
+
+        star.luminosity = star.luminosity_at_age::<Gigayear>(age);
+        star.temperature = Temperature::<Kelvin>::new(
+            Self::SOLAR_TEMPERATURE * (star.luminosity.value() / radius.powi(2)).powf(0.25),
+        );
+        star
+    }
+
+    /// Die mittlere Dichte des Sterns in kg/m³.
+    pub fn mean_density(&self) -> f64 {
+        mean_density_of(
+            self.mass.convert_to::<Kilogram>().value(),
+            self.radius.convert_to::<Meter>().value(),
+        )
+    }
+
+    /// Die Schwerebeschleunigung an der Sternoberfläche.
+    pub fn surface_gravity(&self) -> Acceleration<MeterPerSecondSquared> {
+        surface_gravity_of(
+            self.mass.convert_to::<Kilogram>().value(),
+            self.radius.convert_to::<Meter>().value(),
+        )
+    }
+
+    /// Die Leuchtkraft im Alter `age` während der Hauptreihe.
+    ///
+    /// Verallgemeinert die Beziehung von Gough (1981) für die Sonne,
+    /// L(t) = L_ref / (1 + 2/5 · (1 − t/t_ref)), wobei `luminosity` als Wert beim
+    /// gleichen Anteil der Hauptreihen-Lebensdauer gilt wie bei der heutigen Sonne
+    /// (4.6 von 10 Gyr). Nach dem Ende der Hauptreihe bleibt die Leuchtkraft auf
+    /// dem Endwert stehen.
+    pub fn luminosity_at_age<U>(&self, age: Time<U>) -> Power<SolarLuminosity>
+    where
+        Time<U>: ToSI,
+    {
+        let lifetime = self.main_sequence_lifetime().value();
+        let age_gyr = age.convert_to::<Gigayear>().value().clamp(0.0, lifetime);
+        let reference_age = Self::SOLAR_AGE_GYR / 10.0 * lifetime;
+        self.luminosity / (1.0 + 0.4 * (1.0 - age_gyr / reference_age))
+    }
+
+    /// Die konservative habitable Zone nach Kasting et al. (1993) für eine
+    /// Leuchtkraft: Innenrand bei S = 1.1 S⊕, Außenrand bei S = 0.53 S⊕.
+    fn habitable_zone_for(luminosity: f64) -> HabitableZone {
+        HabitableZone {
+            inner_edge: Distance::<AstronomicalUnit>::new((luminosity / 1.1).sqrt()),
+            outer_edge: Distance::<AstronomicalUnit>::new((luminosity / 0.53).sqrt()),
+        }
+    }
+
+    /// Die habitable Zone bei der aktuellen Leuchtkraft.
+    pub fn habitable_zone(&self) -> HabitableZone {
+        Self::habitable_zone_for(self.luminosity.value())
+    }
+
+    /// Der Bereich, der von `start_age` bis `end_age` durchgehend habitabel bleibt.
+    ///
+    /// Die Leuchtkraft wird über das Intervall entwickelt und die habitablen Zonen
+    /// aller Stützstellen werden geschnitten. Überlappen sie nicht, ist das
+    /// Ergebnis eine leere Zone mit gleichem Innen- und Außenrand.
+    pub fn continuously_habitable_zone<S, E>(
+        &self,
+        start_age: Time<S>,
+        end_age: Time<E>,
+    ) -> HabitableZone
+    where
+        Time<S>: ToSI,
+        Time<E>: ToSI,
+    {
+        const SAMPLES: usize = 64;
+        let start = start_age.convert_to::<Gigayear>().value();
+        let end = end_age.convert_to::<Gigayear>().value();
+
+        let (inner, outer) = (0..=SAMPLES)
+            .map(|i| start + (end - start) * i as f64 / SAMPLES as f64)
+            .map(|age| {
+                let luminosity = self.luminosity_at_age(Time::<Gigayear>::new(age));
+                Self::habitable_zone_for(luminosity.value())
+            })
+            .fold((0.0_f64, f64::INFINITY), |(inner, outer), zone| {
+                (
+                    inner.max(zone.inner_edge.value()),
+                    outer.min(zone.outer_edge.value()),
+                )
+            });
+
+        let outer = outer.max(inner);
+        HabitableZone {
+            inner_edge: Distance::<AstronomicalUnit>::new(inner.min(outer)),
+            outer_edge: Distance::<AstronomicalUnit>::new(outer),
+        }
+    }
+
+    /// Das Alter, in dem ein Planet im festen Abstand `orbital_distance` in die
+    /// habitable Zone eintritt, und das, in dem er sie wieder verlässt.
+    ///
+    /// Die Leuchtkraft wird über die Hauptreihe entwickelt, während die Zone nach
+    /// außen wandert. Liegt der Planet nie in der Zone, ist das Ergebnis `None`;
+    /// bleibt er immer darin, reicht das Fenster von 0 bis zum Ende der Hauptreihe.
+    pub fn habitable_zone_window<U>(
+        &self,
+        orbital_distance: Distance<U>,
+    ) -> Option<(Time<Gigayear>, Time<Gigayear>)>
+    where
+        Distance<U>: ToSI,
+    {
+        const SAMPLES: usize = 1000;
+        let lifetime = self.main_sequence_lifetime().value();
+        let distance = orbital_distance.convert_to::<AstronomicalUnit>();
+
+        let inside_ages: Vec<f64> = (0..=SAMPLES)
+            .map(|i| lifetime * i as f64 / SAMPLES as f64)
+            .filter(|&age| {
+                let luminosity = self.luminosity_at_age(Time::<Gigayear>::new(age));
+                Self::habitable_zone_for(luminosity.value()).contains::<AstronomicalUnit>(distance)
+            })
+            .collect();
+        let (entry, exit) = (inside_ages.first()?, inside_ages.last()?);
+        Some((Time::<Gigayear>::new(*entry), Time::<Gigayear>::new(*exit)))
+    }
+
+    /// Die Schneegrenze, jenseits der Wassereis kondensiert: r ≈ 2.7 AE · √(L/L☉).
+    pub fn snow_line(&self) -> Distance<AstronomicalUnit> {
+        Distance::<AstronomicalUnit>::new(2.7 * self.luminosity.value().sqrt())
+    }
+
+    /// Der Abstand, ab dem ein Stoff mit der gegebenen Kondensationstemperatur
+    /// ausfriert.
+    ///
+    /// Ein schnell rotierender Schwarzkörper hat bei 1 AE um die Sonne 278.6 K, mit
+    /// T ∝ L^¼ / √r. Für Wasser (≈ 170 K) ergibt sich die Schneegrenze.
+    pub fn frost_line_for_species<U>(
+        &self,
+        condensation_temperature: Temperature<U>,
+    ) -> Distance<AstronomicalUnit>
+    where
+        Temperature<U>: ToSI,
+    {
+        let temperature_at_1_au = 278.6 * self.luminosity.value().powf(0.25);
+        Distance::<AstronomicalUnit>::new(
+            (temperature_at_1_au / condensation_temperature.to_si()).powi(2),
+        )
+    }
+
+    /// Die Hauptreihen-Lebensdauer aus Brennstoffvorrat und Verbrauchsrate
+    /// (t ∝ M/L, normiert auf 10 Gyr für die Sonne).
+    ///
+    /// Oberhalb von 15 M☉ trägt der Wind einen merklichen Teil des Brennstoffs
+    /// ab: Bei konstanter Massenverlustrate verkürzt sich die Lebensdauer auf
+    /// t = t₀ / (1 + Ṁ·t₀/M).
+    pub fn main_sequence_lifetime(&self) -> Time<Gigayear> {
+        let mass = self.mass.value();
+        let lifetime = 10.0 * mass / self.luminosity.value();
+        if mass <= Self::MIN_WIND_LIFETIME_MASS {
+            return Time::<Gigayear>::new(lifetime);
+        }
+        let lost_fraction = self.mass_loss_rate().value() * lifetime * 1e9 / mass;
+        Time::<Gigayear>::new(lifetime / (1.0 + lost_fraction))
+    }
+
+    /// Die Massenverlustrate durch den Sternwind.
+    ///
+    /// Heiße Sterne (T ≥ 25 000 K) folgen der Vink-Formel (Vink et al. 2001) mit
+    /// v∞/v_esc = 2.6, kühlere Sterne der Reimers-Beziehung
+    /// Ṁ = η · 4·10⁻¹³ · L·R/M M☉/Jahr mit η = 0.5.
+    pub fn mass_loss_rate(&self) -> MassFlowRate<SolarMassPerYear> {
+        let mass = self.mass.value();
+        let radius = self.radius.value();
+        let luminosity = self.luminosity.value();
+        let temperature = self.temperature.value();
+
+        let rate = if temperature >= Self::MIN_LINE_DRIVEN_WIND_TEMPERATURE {
+            let log_temperature = (temperature / 40_000.0).log10();
+            10f64.powf(
+                -6.697 + 2.194 * (luminosity / 1e5).log10()
+                    - 1.313 * (mass / 30.0).log10()
+                    - 1.226 * (2.6_f64 / 2.0).log10()
+                    + 0.933 * log_temperature
+                    - 10.92 * log_temperature.powi(2),
+            )
+        } else {
+            0.5 * 4e-13 * luminosity * radius / mass
+        };
+        MassFlowRate::<SolarMassPerYear>::new(rate)
+    }
+
+    /// Die absolute bolometrische Helligkeit M_bol = 4.74 − 2.5 · log₁₀(L/L☉).
+    ///
+    /// Sterne ohne positive Leuchtkraft sind unendlich schwach (`f64::INFINITY`).
+    pub fn absolute_bolometric_magnitude(&self) -> f64 {
+        let luminosity = self.luminosity.value();
+        if luminosity > 0.0 {
+            Self::SOLAR_BOLOMETRIC_MAGNITUDE - 2.5 * luminosity.log10()
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    /// Der scheinbare Winkelradius aus der Entfernung `distance`, θ = arcsin(R/d).
+    pub fn angular_radius<D>(&self, distance: Distance<D>) -> Angle<Radian>
+    where
+        Distance<D>: ToSI,
+    {
+        let radius = self.radius.convert_to::<Meter>().value();
+        Angle::<Radian>::new((radius / distance.to_si()).min(1.0).asin())
+    }
+
+    /// Die spektrale Leuchtkraft L_λ in W/nm bei der Wellenlänge `wavelength_nm`.
+    ///
+    /// Die Planck-Funktion B_λ(T) bei der Effektivtemperatur wird mit
+    /// L · π · B_λ / (σT⁴) auf die Leuchtkraft skaliert, sodass das Integral über
+    /// alle Wellenlängen genau L ergibt.
+    pub fn blackbody_flux(&self, wavelength_nm: f64) -> f64 {
+        let h = f64::from(PLANCK_CONSTANT);
+        let c = f64::from(SPEED_OF_LIGHT);
+        let k_b = f64::from(BOLTZMANN_CONSTANT);
+        let temperature = self.temperature.value();
+        let wavelength = wavelength_nm * 1e-9;
+
+        let planck = 2.0 * h * c * c
+            / wavelength.powi(5)
+            / ((h * c / (wavelength * k_b * temperature)).exp() - 1.0);
+        let luminosity = self.luminosity.convert_to::<Watt>().value();
+        luminosity * std::f64::consts::PI * planck
+            / (f64::from(STEFAN_BOLTZMANN) * temperature.powi(4))
+            * 1e-9
+    }
+
+    /// Die spektrale Energieverteilung: [`Self::blackbody_flux`] für jede der
+    /// Wellenlängen `wavelengths` (in nm).
+    pub fn sed(&self, wavelengths: &[f64]) -> Vec<f64> {
+        wavelengths
+            .iter()
+            .map(|&wavelength| self.blackbody_flux(wavelength))
+            .collect()
+    }
+
+    /// Die bolometrische Korrektur BC_V = M_bol − M_V aus der Effektivtemperatur.
+    ///
+    /// Polynome in log₁₀ T nach Flower (1996) mit den korrigierten Koeffizienten
+    /// von Torres (2010). Heiße und kühle Sterne strahlen vor allem außerhalb des
+    /// V-Bands und haben stark negative Korrekturen; die Sonne liegt bei ≈ −0.08.
+    pub fn bolometric_correction(&self) -> f64 {
+        const COOL: [f64; 4] = [
+            -0.190537291496456e5,
+            0.155144866764412e5,
+            -0.421278819301717e4,
+            0.381476328422343e3,
+        ];
+        const INTERMEDIATE: [f64; 5] = [
+            -0.370510203809015e5,
+            0.385672629965804e5,
+            -0.150651486316025e5,
+            0.261724637119416e4,
+            -0.170623810323864e3,
+        ];
+        const HOT: [f64; 6] = [
+            -0.118115450538963e6,
+            0.137145973583929e6,
+            -0.636233812100225e5,
+            0.147412923562646e5,
+            -0.170587278406872e4,
+            0.788731721804990e2,
+        ];
+        let log_temperature = self.temperature.value().log10();
+        let coefficients: &[f64] = if log_temperature < 3.70 {
+            &COOL
+        } else if log_temperature < 3.90 {
+            &INTERMEDIATE
+        } else {
+            &HOT
+        };
+        coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |sum, &coefficient| sum * log_temperature + coefficient)
+    }
+
+    /// Der Anteil der Leuchtkraft im visuellen Band, L_V = L · 10^(0.4 · BC_V).
+    pub fn visual_luminosity(&self) -> Power<SolarLuminosity> {
+        self.luminosity * 10f64.powf(0.4 * self.bolometric_correction())
+    }
+
+    /// Die scheinbare (bolometrische) Helligkeit aus der Entfernung, über den
+    /// Entfernungsmodul m − M = 5 · log₁₀(d / 10 pc).
+    pub fn apparent_magnitude<D>(&self, distance: Distance<D>) -> f64
+    where
+        Distance<D>: ToSI,
+    {
+        let parsecs = distance.convert_to::<Parsec>().value();
+        self.absolute_bolometric_magnitude() + 5.0 * (parsecs / 10.0).log10()
+    }
+
+    /// Der Farbindex B−V aus der Effektivtemperatur.
+    ///
+    /// Invertiert die Beziehung von Ballesteros (2012),
+    /// T = 4600 K · (1/(0.92·(B−V) + 1.7) + 1/(0.92·(B−V) + 0.62)),
+    /// die mit x = 0.92·(B−V) eine quadratische Gleichung in x ergibt.
+    pub fn color_index_bv(&self) -> f64 {
+        let k = self.temperature.value() / 4600.0;
+        let b = 2.32 * k - 2.0;
+        let c = 1.054 * k - 2.32;
+        let x = (-b + (b * b - 4.0 * k * c).sqrt()) / (2.0 * k);
+        x / 0.92
+    }
+
+    /// Eine genäherte Darstellungsfarbe aus dem Farbindex B−V, linear interpoliert
+    /// zwischen typischen Sternfarben von blauen O- bis roten M-Sternen.
+    pub fn rgb_approximation(&self) -> (u8, u8, u8) {
+        const PALETTE: [(f64, [f64; 3]); 8] = [
+            (-0.4, [155.0, 176.0, 255.0]),
+            (-0.2, [170.0, 191.0, 255.0]),
+            (0.0, [202.0, 215.0, 255.0]),
+            (0.3, [248.0, 247.0, 255.0]),
+            (0.6, [255.0, 244.0, 234.0]),
+            (1.0, [255.0, 210.0, 161.0]),
+            (1.5, [255.0, 187.0, 123.0]),
+            (2.0, [255.0, 159.0, 70.0]),
+        ];
+        let bv = self
+            .color_index_bv()
+            .clamp(PALETTE[0].0, PALETTE[PALETTE.len() - 1].0);
+        let upper = PALETTE
+            .iter()
+            .position(|&(index, _)| index >= bv)
+            .unwrap_or(PALETTE.len() - 1)
+            .max(1);
+        let (low_bv, low) = PALETTE[upper - 1];
+        let (high_bv, high) = PALETTE[upper];
+        let t = (bv - low_bv) / (high_bv - low_bv);
+        let [r, g, b] = [0, 1, 2].map(|i| (low[i] + t * (high[i] - low[i])).round() as u8);
+        (r, g, b)
+    }
+
+    /// Der Weiße Zwerg, zu dem sich der Stern bis zum Alter `age` entwickelt hat.
+    ///
+    /// Sterne unter 8 M☉ werden nach Hauptreihe und einer Riesenphase von 10 % der
+    /// Hauptreihen-Lebensdauer zu Weißen Zwergen. Die Restmasse folgt der
+    /// Anfangs-End-Massenbeziehung von Kalirai et al. (2008), die Leuchtkraft dem
+    /// Mestel-Abkühlungsgesetz L ∝ M · t^(-7/5) und der Radius der Masse-Radius-
+    /// Beziehung R ∝ M^(-1/3) (etwa Erdgröße). Gibt `None` zurück, solange der Stern
+    /// noch brennt oder zu massereich für einen Weißen Zwerg ist.
+    pub fn white_dwarf_at_age<U>(&self, age: Time<U>) -> Option<StarData>
+    where
+        Time<U>: ToSI,
+    {
+        let mass = self.mass.value();
+        let end_of_giant_phase = 1.1 * self.main_sequence_lifetime().value();
+        let cooling_age = age.convert_to::<Gigayear>().value() - end_of_giant_phase;
+        if mass >= Self::MAX_WHITE_DWARF_PROGENITOR_MASS || cooling_age <= 0.0 {
+            return None;
+        }
+
+        let remnant_mass = 0.109 * mass + 0.394;
+        let luminosity =
+            Self::MESTEL_LUMINOSITY_1_GYR * (remnant_mass / 0.6) * cooling_age.max(1e-3).powf(-1.4);
+        let radius = Self::WHITE_DWARF_RADIUS_0_6 * (remnant_mass / 0.6).powf(-1.0 / 3.0);
+        let temperature = Self::SOLAR_TEMPERATURE * (luminosity / radius.powi(2)).powf(0.25);
+
+        Some(StarData {
+            mass: Mass::<SolarMass>::new(remnant_mass),
+            radius: Distance::<SunRadius>::new(radius),
+            temperature: Temperature::<Kelvin>::new(temperature),
+            luminosity: Power::<SolarLuminosity>::new(luminosity),
+            spectral_type: SpectralType::D,
+            luminosity_class: LuminosityClass::VII,
+        })
+    }
+
+    /// Spektraltyp und Leuchtkraftklasse in Standardnotation, z.B. "G2V".
+    ///
+    /// Weiße Zwerge und Wolf-Rayet-Sterne werden ohne Leuchtkraftklasse notiert.
+    pub fn spectral_classification(&self) -> String {
+        match self.spectral_type {
+            SpectralType::D | SpectralType::WR => self.spectral_type.to_string(),
+            _ => format!("{}{}", self.spectral_type, self.luminosity_class),
+        }
+    }
+
+    fn is_white_dwarf(&self) -> bool {
+        self.spectral_type == SpectralType::D || self.luminosity_class == LuminosityClass::VII
+    }
+
+    fn is_giant(&self) -> bool {
+        matches!(
+            self.luminosity_class,
+            LuminosityClass::Ia | LuminosityClass::Ib | LuminosityClass::II | LuminosityClass::III
+        )
+    }
+
+    /// Kurzbeschreibung für Paare mit kompakten Begleitern, z.B. "M-dwarf".
+    fn pair_description(&self) -> String {
+        match (&self.spectral_type, &self.luminosity_class) {
+            _ if self.is_white_dwarf() => "white-dwarf".to_string(),
+            (SpectralType::M(_), LuminosityClass::V) => "M-dwarf".to_string(),
+            _ => self.spectral_classification(),
+        }
+    }
+
+    /// Rotationsperiode der Sonne im heutigen Alter, Eichpunkt der Gyrochronologie.
+    const SOLAR_ROTATION_DAYS: f64 = 25.4;
+    /// Alter der Sonne in Gyr.
+    const SOLAR_AGE_GYR: f64 = 4.6;
+    /// Sättigungsniveau von L_X/L_bol für schnelle Rotatoren.
+    const SATURATED_XRAY_FRACTION: f64 = 1.0e-3;
+    /// Rossby-Zahl, unterhalb derer die Röntgenaktivität gesättigt ist.
+    const SATURATION_ROSSBY: f64 = 0.13;
+    /// Flares pro Tag (E > 10³² erg) eines gesättigten Sterns.
+    const SATURATED_FLARES_PER_DAY: f64 = 10.0;
+
+    /// Masse (M☉), oberhalb der Sterne keine konvektive Hülle und damit kaum
+    /// magnetisches Bremsen haben.
+    const MAX_SPIN_DOWN_MASS: f64 = 1.3;
+    /// Rotationsperiode schneller Rotatoren (junge und heiße Sterne) in Tagen.
+    const FAST_ROTATION_DAYS: f64 = 1.5;
+
+    /// Die Dauer der Vor-Hauptreihenphase; masseärmere Sterne kontrahieren länger.
+    fn pre_main_sequence_duration(&self) -> Time<Gigayear> {
+        Time::<Gigayear>::new(0.04 * self.mass.value().powf(-1.5))
+    }
+
+    /// Die Rotationsperiode im Alter `age` nach Gyrochronologie.
+    ///
+    /// Während der Vor-Hauptreihenphase rotiert der Stern schnell. Danach bremst
+    /// ihn sein Wind nach Skumanich (P ∝ t^0.52) ab, geeicht auf die Sonne (25.4 d
+    /// bei 4.6 Gyr); der Massenterm M^-0.6 bildet den Farbterm von Barnes (2007)
+    /// nach, sodass kühlere Sterne langsamer rotieren.
+    pub fn rotation_period_at_age<U>(&self, age: Time<U>) -> Time<Day>
+    where
+        Time<U>: ToSI,
+    {
+        let mass = self.mass.value();
+        let age_gyr = age.convert_to::<Gigayear>().value();
+        // Sterne ohne konvektive Hülle verlieren kaum Drehimpuls.
+        if mass > Self::MAX_SPIN_DOWN_MASS {
+            return Time::<Day>::new(Self::FAST_ROTATION_DAYS);
+        }
+        if age_gyr < self.pre_main_sequence_duration().value() {
+            return Time::<Day>::new(Self::FAST_ROTATION_DAYS);
+        }
+
+        let skumanich = Self::SOLAR_ROTATION_DAYS
+            * (age_gyr / Self::SOLAR_AGE_GYR).powf(0.52)
+            * mass.powf(-0.6);
+        Time::<Day>::new(skumanich.max(0.3))
+    }
+
+    /// Das gyrochronologische Alter zu einer gemessenen Rotationsperiode, die
+    /// Umkehrung von [`Self::rotation_period_at_age`].
+    ///
+    /// Gibt `None` für Sterne ohne magnetisches Bremsen zurück, deren Rotation
+    /// nichts über ihr Alter verrät.
+    pub fn gyrochronology_age<U>(&self, rotation_period: Time<U>) -> Option<Time<Gigayear>>
+    where
+        Time<U>: ToSI,
+    {
+        let mass = self.mass.value();
+        if mass > Self::MAX_SPIN_DOWN_MASS {
+            return None;
+        }
+        let rotation_days = rotation_period.convert_to::<Day>().value();
+        let scaled = rotation_days * mass.powf(0.6) / Self::SOLAR_ROTATION_DAYS;
+        let age = Self::SOLAR_AGE_GYR * scaled.powf(1.0 / 0.52);
+        Some(Time::<Gigayear>::new(
+            age.max(self.pre_main_sequence_duration().value()),
+        ))
+    }
+
+    /// Die magnetische Aktivität des Sterns in einem bestimmten Alter.
+    ///
+    /// Rotation, Röntgenanteil und Flare-Rate werden gemeinsam aus dem
+    /// Skumanich-Spin-down (P ∝ √t) und der daraus folgenden Rossby-Zahl abgeleitet.
+    pub fn activity<U>(&self, age: Time<U>) -> StellarActivity
+    where
+        Time<U>: ToSI,
+    {
+        let mass = self.mass.value();
+        let rotation_days = self.rotation_period_at_age(age).value();
+
+        // Konvektive Umschlagzeit nach Wright et al. (2011), in Tagen
+        let log_m = mass.log10();
+        let convective_turnover_days = 10f64.powf(1.16 - 1.49 * log_m - 0.54 * log_m.powi(2));
+        let rossby_number = rotation_days / convective_turnover_days;
+
+        let xray_luminosity_fraction = if rossby_number < Self::SATURATION_ROSSBY {
+            Self::SATURATED_XRAY_FRACTION
+        } else {
+            Self::SATURATED_XRAY_FRACTION * (rossby_number / Self::SATURATION_ROSSBY).powi(-2)
+        };
+
+        StellarActivity {
+            rotation_period: Time::<Day>::new(rotation_days),
+            rossby_number,
+            xray_luminosity_fraction,
+            flare_frequency: Self::SATURATED_FLARES_PER_DAY * xray_luminosity_fraction
+                / Self::SATURATED_XRAY_FRACTION,
+        }
+    }
+
+    /// Untere Energiegrenze (erg) der in `flare_frequency` gezählten Flares.
+    const FLARE_MIN_ENERGY_ERG: f64 = 1.0e32;
+    /// Obergrenze (erg) für Superflares.
+    const FLARE_MAX_ENERGY_ERG: f64 = 1.0e36;
+    /// Exponent α der Energieverteilung dN/dE ∝ E^-α.
+    const FLARE_POWER_LAW_INDEX: f64 = 1.8;
+    /// Dauer eines Flares an der unteren Energiegrenze in Minuten.
+    const FLARE_MIN_DURATION_MINUTES: f64 = 10.0;
+
+    /// Erzeugt die Flares, die der Stern im Alter `age` während `duration` zeigt.
+    ///
+    /// Die Flares bilden einen Poisson-Prozess mit der Rate `flare_frequency` aus
+    /// [`Self::activity`], sodass aktive M-Zwerge deutlich häufiger flaren. Die
+    /// Energien folgen dem Potenzgesetz dN/dE ∝ E^-1.8 ab 10³² erg, die Dauer
+    /// wächst nach Maehara et al. (2015) mit E^0.39.
+    pub fn generate_flares<A, D, R: Rng + ?Sized>(
+        &self,
+        age: Time<A>,
+        duration: Time<D>,
+        rng: &mut R,
+    ) -> Vec<FlareEvent>
+    where
+        Time<A>: ToSI,
+        Time<D>: ToSI,
+    {
+        let rate_per_day = self.activity(age).flare_frequency;
+        let total_days = duration.convert_to::<Day>().value();
+        let mut flares = Vec::new();
+        if rate_per_day <= 0.0 {
+            return flares;
+        }
+
+        let mut time_days = 0.0;
+        loop {
+            time_days += -(1.0 - rng.r#gen::<f64>()).ln() / rate_per_day;
+            if time_days > total_days {
+                break;
+            }
+            let energy = (Self::FLARE_MIN_ENERGY_ERG
+                * (1.0 - rng.r#gen::<f64>()).powf(-1.0 / (Self::FLARE_POWER_LAW_INDEX - 1.0)))
+            .min(Self::FLARE_MAX_ENERGY_ERG);
+            let minutes =
+                Self::FLARE_MIN_DURATION_MINUTES * (energy / Self::FLARE_MIN_ENERGY_ERG).powf(0.39);
+            flares.push(FlareEvent {
+                time_offset: Time::<Day>::new(time_days),
+                energy: Energy::<Erg>::new(energy),
+                duration: Time::<Minute>::new(minutes),
+            });
+        }
+        flares
+    }
+
+    /// Staudruck des Sonnenwinds bei 1 AU in Pa (n ≈ 5 cm⁻³, v ≈ 400 km/s).
+    const SOLAR_WIND_PRESSURE_1AU: f64 = 1.34e-9;
+    /// L_X/L_bol der heutigen Sonne im Aktivitätsmodell.
+    const SOLAR_XRAY_FRACTION: f64 = 5.5e-6;
+
+    /// Der Staudruck des Sternwinds im Abstand `distance`.
+    ///
+    /// Die Massenverlustrate folgt Ṁ ∝ R²·F_X^1.34 (Wood et al. 2005) und ist auf
+    /// die heutige Sonne geeicht; die Windgeschwindigkeit wird als konstant angenommen.
+    pub fn stellar_wind_pressure<D, U>(
+        &self,
+        distance: Distance<D>,
+        age: Time<U>,
+    ) -> Pressure<Pascal>
+    where
+        Distance<D>: ToSI,
+        Time<U>: ToSI,
+    {
+        let radius = self.radius.value();
+        let xray_flux_ratio = self.activity(age).xray_luminosity_fraction * self.luminosity.value()
+            / (Self::SOLAR_XRAY_FRACTION * radius * radius);
+        let mass_loss_ratio = radius * radius * xray_flux_ratio.powf(1.34);
+        let distance_au = distance.to_si() / METERS_PER_AU;
+
+        Pressure::<Pascal>::new(
+            Self::SOLAR_WIND_PRESSURE_1AU * mass_loss_ratio / (distance_au * distance_au),
+        )
+    }
+}
+
+impl PlanetData {
+    /// Das magnetische Dipolmoment der Erde in A·m².
+    const EARTH_MAGNETIC_MOMENT: f64 = 8.0e22;
+    /// Massenanteil des Erdkerns.
+    const EARTH_CORE_MASS_FRACTION: f64 = 0.325;
+    /// Trägheitsmomentfaktor I/(m·R²) eines differenzierten Gesteinsplaneten.
+    const MOMENT_OF_INERTIA_FACTOR: f64 = 0.33;
+    /// Kompressionsfaktor des Dipolfelds an der Magnetopause.
+    const MAGNETOPAUSE_FORM_FACTOR: f64 = 1.16;
+    /// Mindestabstand der Magnetopause in Planetenradien, der die Atmosphäre schützt.
+    const SHIELDING_STANDOFF_RADII: f64 = 2.0;
+
+    /// Dichte von Ringpartikeln aus Wassereis in kg/m³.
+    const RING_PARTICLE_DENSITY: f64 = 900.0;
+    /// Angenommenes k₂/Q für Gezeitenreibung (Io: ≈ 0.015, Erde: ≈ 0.025).
+    const TIDAL_K2_OVER_Q: f64 = 0.02;
+    /// Verhältnis von Exobasis- zu Gleichgewichtstemperatur durch XUV-Heizung.
+    const EXOBASE_HEATING_FACTOR: f64 = 4.0;
+    /// Teilchendichte an der Exobasis in m⁻³.
+    const EXOBASE_DENSITY: f64 = 1.0e12;
+    /// Masse eines Sauerstoffatoms in kg.
+    const OXYGEN_MASS: f64 = 16.0 * 1.660_539e-27;
+    /// Anteil der absorbierten XUV-Energie, der die Flucht antreibt.
+    const ESCAPE_HEATING_EFFICIENCY: f64 = 0.15;
+    /// Bond-Albedo eines Mondes für die Energiebilanz.
+    const MOON_ALBEDO: f64 = 0.3;
+    /// Die übliche Bond-Albedo eines erdähnlichen Planeten.
+    pub const DEFAULT_ALBEDO: f64 = 0.3;
+    /// Bond-Albedo einer geschlossenen Eisdecke.
+    const ICE_ALBEDO: f64 = 0.6;
+
+    fn default_rotation_period() -> Time<Hour> {
+        Time::<Hour>::new(24.0)
+    }
+
+    /// Der mittlere Gezeitenwärmefluss durch die Oberfläche in W/m².
+    ///
+    /// Der Planet wird als gebunden rotierend auf der Bahn `orbit` um einen
+    /// Zentralkörper der Masse `host_mass` angenommen; geheizt wird durch die
+    /// Exzentrizität.
+    pub fn tidal_heating_flux<U>(&self, host_mass: Mass<U>, orbit: &Orbit) -> f64
+    where
+        Mass<U>: ToSI,
+    {
+        let radius = self.radius.convert_to::<Meter>();
+        let power = calculate_tidal_heating(
+            host_mass,
+            radius,
+            orbit.semi_major_axis,
+            orbit.eccentricity,
+            Self::TIDAL_K2_OVER_Q,
+        );
+        power.value() / (4.0 * std::f64::consts::PI * radius.value().powi(2))
+    }
+
+    /// Die Zeit bis zur gebundenen Rotation auf der Bahn `orbit` um einen
+    /// Zentralkörper der Masse `host_mass`.
+    ///
+    /// Nach Gladman et al. (1996): t = ω·a⁶·I·Q / (3·G·M²·k₂·R⁵) mit der heutigen
+    /// Rotation ω und I = 0.33·m·R².
+    pub fn tidal_locking_time<U>(&self, host_mass: Mass<U>, orbit: &Orbit) -> Time<Gigayear>
+    where
+        Mass<U>: ToSI,
+    {
+        let mass = self.mass.convert_to::<Kilogram>().value();
+        let radius = self.radius.convert_to::<Meter>().value();
+        let a = orbit.semi_major_axis.convert_to::<Meter>().value();
+        let spin_rate =
+            2.0 * std::f64::consts::PI / self.rotation_period.convert_to::<Second>().value();
+        let inertia = Self::MOMENT_OF_INERTIA_FACTOR * mass * radius * radius;
+
+        Time::<Second>::new(
+            spin_rate * a.powi(6) * inertia
+                / (3.0
+                    * f64::from(G)
+                    * host_mass.to_si().powi(2)
+                    * Self::TIDAL_K2_OVER_Q
+                    * radius.powi(5)),
+        )
+        .convert_to::<Gigayear>()
+    }
+
+    /// Die Rotationsperiode im Alter `age`: die Umlaufperiode, sobald die
+    /// Rotation nach [`Self::tidal_locking_time`] gebunden ist, sonst die eigene.
+    pub fn spin_period<U, A>(&self, host_mass: Mass<U>, orbit: &Orbit, age: Time<A>) -> Time<Hour>
+    where
+        Mass<U>: ToSI,
+        Time<A>: ToSI,
+    {
+        let host_mass = host_mass.convert_to::<Kilogram>();
+        let locking_time = self.tidal_locking_time::<Kilogram>(host_mass, orbit);
+        if age.convert_to::<Gigayear>().value() >= locking_time.value() {
+            orbit
+                .orbital_period::<Kilogram>(host_mass + self.mass.convert_to::<Kilogram>())
+                .convert_to::<Hour>()
+        } else {
+            self.rotation_period
+        }
+    }
+
+    /// Geschätztes magnetisches Dipolmoment (A·m²) mit dem Kernanteil der Erde
+    /// und der eigenen Rotationsperiode, ohne Gezeitenbindung.
+    pub fn magnetic_moment_estimate<U>(&self, age: Time<U>) -> f64
+    where
+        Time<U>: ToSI,
+    {
+        self.dynamo_moment(
+            age.convert_to::<Gigayear>(),
+            Self::EARTH_CORE_MASS_FRACTION,
+            self.rotation_period,
+        )
+    }
+
+    /// Geschätztes magnetisches Dipolmoment (A·m²) für einen Kern mit dem
+    /// Massenanteil `core_mass_fraction` (Erde: 0.325) auf der Bahn `orbit` um
+    /// einen Zentralkörper der Masse `host_mass`.
+    ///
+    /// Die Rotation folgt [`Self::spin_period`]: gebunden rotierende Planeten
+    /// mit Perioden von Tagen bis Wochen erzeugen ein deutlich schwächeres Feld.
+    pub fn magnetic_moment_for_core<U, M>(
+        &self,
+        age: Time<U>,
+        core_mass_fraction: f64,
+        host_mass: Mass<M>,
+        orbit: &Orbit,
+    ) -> f64
+    where
+        Time<U>: ToSI,
+        Mass<M>: ToSI,
+    {
+        let age = age.convert_to::<Gigayear>();
+        let rotation_period = self.spin_period::<M, Gigayear>(host_mass, orbit, age);
+        self.dynamo_moment(age, core_mass_fraction, rotation_period)
+    }
+
+    /// Dynamo-Skalierung nach Mizutani et al. (1992): ρ_c^½ · ω^½ · r_c³ relativ
+    /// zur Erde, mit r_c ∝ m_c^¼. Ohne aktiven Kern gibt es keinen Dynamo.
+    fn dynamo_moment(
+        &self,
+        age: Time<Gigayear>,
+        core_mass_fraction: f64,
+        rotation_period: Time<Hour>,
+    ) -> f64 {
+        if !self.active_core.0 || core_mass_fraction <= 0.0 {
+            return 0.0;
+        }
+
+        let core_mass = self.mass.value() * core_mass_fraction / Self::EARTH_CORE_MASS_FRACTION;
+        let core_radius = core_mass.powf(0.25);
+        let core_density = core_mass / core_radius.powi(3);
+        let rotation_rate = 24.0 / rotation_period.value();
+
+        Self::EARTH_MAGNETIC_MOMENT
+            * core_density.sqrt()
+            * rotation_rate.sqrt()
+            * core_radius.powi(3)
+            * self.dynamo_cooling(age)
+    }
+
+    /// Abschwächung des Dynamos nach Ablauf seiner Lebensdauer: kleinere
+    /// Planeten kühlen schneller aus, ihr Dynamo erlischt früher.
+    fn dynamo_cooling(&self, age: Time<Gigayear>) -> f64 {
+        let age_gyr = age.value();
+        let dynamo_lifetime_gyr = 10.0 * self.mass.value().sqrt();
+        (-(age_gyr - dynamo_lifetime_gyr).max(0.0) / 2.0).exp()
+    }
+
+    /// Der Abstand der Magnetopause vom Planetenzentrum (Chapman-Ferraro-Gleichgewicht
+    /// zwischen magnetischem Druck und Staudruck des Sternwinds).
+    ///
+    /// Das Dipolmoment folgt [`Self::magnetic_moment_for_core`] mit dem Kernanteil
+    /// der Erde auf einer Kreisbahn im Abstand `distance`, ist also bei
+    /// gebundener Rotation entsprechend schwach.
+    pub fn magnetopause_standoff<D, U>(
+        &self,
+        star: &StarData,
+        distance: Distance<D>,
+        age: Time<U>,
+    ) -> Distance<Meter>
+    where
+        Distance<D>: ToSI,
+        Time<U>: ToSI,
+    {
+        let mu0 = 4.0e-7 * std::f64::consts::PI;
+        let age = age.convert_to::<Gigayear>();
+        let orbit = Orbit {
+            semi_major_axis: Distance::<AstronomicalUnit>::new(distance.to_si() / METERS_PER_AU),
+            ..Default::default()
+        };
+        let moment = self.magnetic_moment_for_core::<Gigayear, SolarMass>(
+            age,
+            Self::EARTH_CORE_MASS_FRACTION,
+            star.mass,
+            &orbit,
+        );
+        let wind_pressure = star
+            .stellar_wind_pressure::<D, Gigayear>(distance, age)
+            .value();
+
+        Distance::<Meter>::new(
+            (mu0 * Self::MAGNETOPAUSE_FORM_FACTOR.powi(2) * moment * moment
+                / (8.0 * std::f64::consts::PI.powi(2) * wind_pressure))
+                .powf(1.0 / 6.0),
+        )
+    }
+
+    /// Ob das Magnetfeld die Atmosphäre gegen den Sternwind abschirmt.
+    pub fn is_magnetically_shielded<D, U>(
+        &self,
+        star: &StarData,
+        distance: Distance<D>,
+        age: Time<U>,
+    ) -> bool
+    where
+        Distance<D>: ToSI,
+        Time<U>: ToSI,
+    {
+        let standoff = self.magnetopause_standoff(star, distance, age).value();
+        standoff >= Self::SHIELDING_STANDOFF_RADII * self.radius.convert_to::<Meter>().value()
+    }
+
+    /// Der atmosphärische Massenverlust im Abstand `distance` vom Stern `star`.
+    ///
+    /// Die Exobasis ist durch XUV-Heizung viermal so heiß wie die
+    /// Gleichgewichtstemperatur (Erde: ≈ 1000 K); schwere Teilchen entweichen dort
+    /// nach Jeans mit dem Fluchtparameter λ = GMm/(kTR). Wasserstoff geht
+    /// energiebegrenzt verloren, Ṁ = ε·π·F_XUV·R³/(GM) mit ε = 0.15, wobei der
+    /// XUV-Fluss aus der Röntgenaktivität des Sterns im Alter `age` folgt. Die
+    /// energiebegrenzte Rate ist eine Obergrenze.
+    pub fn atmospheric_escape<D, U>(
+        &self,
+        star: &StarData,
+        distance: Distance<D>,
+        age: Time<U>,
+    ) -> AtmosphericEscape
+    where
+        Distance<D>: ToSI,
+        Time<U>: ToSI,
+    {
+        let g = f64::from(G);
+        let k_b = f64::from(BOLTZMANN_CONSTANT);
+        let mass = self.mass.convert_to::<Kilogram>().value();
+        let radius = self.radius.convert_to::<Meter>().value();
+        let distance = distance.to_si();
+        let luminosity = star.luminosity.convert_to::<Watt>().value();
+
+        let equilibrium_temperature =
+            278.6 * star.luminosity.value().powf(0.25) / (distance / METERS_PER_AU).sqrt();
+        let exobase_temperature = Self::EXOBASE_HEATING_FACTOR * equilibrium_temperature;
+        let lambda = g * mass * Self::OXYGEN_MASS / (k_b * exobase_temperature * radius);
+        let thermal_velocity = (2.0 * k_b * exobase_temperature / Self::OXYGEN_MASS).sqrt();
+        let jeans_flux = Self::EXOBASE_DENSITY * thermal_velocity
+            / (2.0 * std::f64::consts::PI.sqrt())
+            * (1.0 + lambda)
+            * (-lambda).exp();
+        let jeans = jeans_flux * 4.0 * std::f64::consts::PI * radius * radius * Self::OXYGEN_MASS;
+
+        let xuv_flux = star.activity(age).xray_luminosity_fraction * luminosity
+            / (4.0 * std::f64::consts::PI * distance * distance);
+        let hydrodynamic =
+            Self::ESCAPE_HEATING_EFFICIENCY * std::f64::consts::PI * xuv_flux * radius.powi(3)
+                / (g * mass);
+
+        AtmosphericEscape {
+            jeans: MassFlowRate::<KilogramPerSecond>::new(jeans),
+            hydrodynamic: MassFlowRate::<KilogramPerSecond>::new(hydrodynamic),
+        }
+    }
+
+    /// Die Energiebilanz dieses Körpers als Mond des Planeten `host` auf der Bahn
+    /// `moon_orbit`, während `host` den Stern `star` auf `host_orbit` umkreist.
+    ///
+    /// Der Planet strahlt die absorbierte Sternenergie als reflektiertes Licht und
+    /// Wärme wieder ab, am Mond zusammen F_⋆ · R_p² / (4 a²). Auf einer koplanaren
+    /// Bahn verbringt der Mond den Anteil arcsin(R_p / a) / π im Schatten. Die
+    /// Gezeitenheizung folgt [`Self::tidal_heating_flux`].
+    pub fn moon_habitability(
+        &self,
+        star: &StarData,
+        host: &PlanetData,
+        host_orbit: &Orbit,
+        moon_orbit: &Orbit,
+    ) -> MoonHabitability {
+        let stefan_boltzmann = f64::from(STEFAN_BOLTZMANN);
+        let host_distance = host_orbit.semi_major_axis.convert_to::<Meter>().value();
+        let moon_distance = moon_orbit.semi_major_axis.convert_to::<Meter>().value();
+        let host_radius = host.radius.convert_to::<Meter>().value();
+
+        let stellar_flux = star.luminosity.convert_to::<Watt>().value()
+            / (4.0 * std::f64::consts::PI * host_distance * host_distance);
+        let planetary_flux = stellar_flux * host_radius.powi(2) / (4.0 * moon_distance.powi(2));
+        let eclipse_fraction = (host_radius / moon_distance).min(1.0).asin() / std::f64::consts::PI;
+        let tidal_heating_flux = self.tidal_heating_flux(host.mass, moon_orbit);
+
+        let absorbed = (1.0 - Self::MOON_ALBEDO)
+            * (stellar_flux * (1.0 - eclipse_fraction) + planetary_flux)
+            / 4.0
+            + tidal_heating_flux;
+        MoonHabitability {
+            stellar_flux,
+            planetary_flux,
+            tidal_heating_flux,
+            eclipse_fraction,
+            equilibrium_temperature: Temperature::<Kelvin>::new(
+                (absorbed / stefan_boltzmann).powf(0.25),
+            ),
+        }
+    }
+
+    /// Die Gleichgewichtstemperatur im Abstand `distance` vom Stern `star` bei
+    /// der Bond-Albedo `albedo` (üblich: [`Self::DEFAULT_ALBEDO`]).
+    ///
+    /// Ein schnell rotierender Schwarzkörper hat bei 1 AE um die Sonne 278.6 K,
+    /// mit T ∝ (1 − A)^¼ · L^¼ / √r.
+    pub fn equilibrium_temperature<D>(
+        star: &StarData,
+        distance: Distance<D>,
+        albedo: f64,
+    ) -> Temperature<Kelvin>
+    where
+        Distance<D>: ToSI,
+    {
+        let distance_au = distance.to_si() / METERS_PER_AU;
+        Temperature::<Kelvin>::new(
+            278.6 * ((1.0 - albedo) * star.luminosity.value()).powf(0.25) / distance_au.sqrt(),
+        )
+    }
+
+    /// Die Oberflächentemperatur mit Albedo und Treibhausfaktor des Planetentyps
+    /// (siehe [`BodyType::surface_model`]).
+    pub fn surface_temperature<D>(
+        &self,
+        star: &StarData,
+        distance: Distance<D>,
+    ) -> Temperature<Kelvin>
+    where
+        Distance<D>: ToSI,
+    {
+        let (albedo, greenhouse) = self.body_type.surface_model();
+        Self::equilibrium_temperature(star, distance, albedo) * greenhouse
+    }
+
+    /// Ob der Planet vollständig vereist bliebe (Eis-Albedo-Rückkopplung).
+    ///
+    /// Mit geschlossener Eisdecke (Albedo 0.6) und dem Treibhausfaktor des
+    /// Planetentyps bleibt die Oberfläche unter dem Gefrierpunkt, sodass ein
+    /// einmal vereister Planet nicht mehr auftaut, wie bei einer Schneeball-Erde.
+    pub fn is_ice_locked<D>(&self, star: &StarData, distance: Distance<D>) -> bool
+    where
+        Distance<D>: ToSI,
+    {
+        let (_, greenhouse) = self.body_type.surface_model();
+        let frozen = Self::equilibrium_temperature(star, distance, Self::ICE_ALBEDO) * greenhouse;
+        frozen.value() < CELSIUS_OFFSET
+    }
+
+    /// Die mittlere Dichte des Planeten in kg/m³.
+    pub fn mean_density(&self) -> f64 {
+        mean_density_of(
+            self.mass.convert_to::<Kilogram>().value(),
+            self.radius.convert_to::<Meter>().value(),
+        )
+    }
+
+    /// Die Schwerebeschleunigung an der Planetenoberfläche.
+    pub fn surface_gravity(&self) -> Acceleration<MeterPerSecondSquared> {
+        surface_gravity_of(
+            self.mass.convert_to::<Kilogram>().value(),
+            self.radius.convert_to::<Meter>().value(),
+        )
+    }
+
+    /// Minimale und maximale tagesgemittelte Einstrahlung (W/m²) über einen Umlauf
+    /// auf der geographischen Breite `latitude`.
+    ///
+    /// Die Jahreszeiten folgen aus der Achsneigung (Deklination des Sterns) und der
+    /// Bahnexzentrizität (Abstand zum Stern). Der Frühlingspunkt wird in Richtung
+    /// der Periapsis angenommen, die solare Länge ist also die wahre Anomalie;
+    /// das Argument der Periapsis der Bahn spielt keine Rolle.
+    pub fn seasonal_insolation_range(
+        &self,
+        star: &StarData,
+        orbit: &Orbit,
+        latitude: Angle<Radian>,
+    ) -> (f64, f64) {
+        const SAMPLES: usize = 360;
+
+        let luminosity = star.luminosity.convert_to::<Watt>().value();
+        let a = orbit.semi_major_axis.convert_to::<Meter>().value();
+        let e = orbit.eccentricity;
+        let obliquity = self.obliquity.value();
+        let phi = latitude.value();
+
+        let mut min = f64::INFINITY;
+        let mut max = f64::NEG_INFINITY;
+        for i in 0..SAMPLES {
+            let true_anomaly = 2.0 * std::f64::consts::PI * i as f64 / SAMPLES as f64;
+            let r = a * (1.0 - e * e) / (1.0 + e * true_anomaly.cos());
+            let flux = luminosity / (4.0 * std::f64::consts::PI * r * r);
+
+            let declination = (obliquity.sin() * true_anomaly.sin()).asin();
+
+            // Stundenwinkel des Sonnenuntergangs, begrenzt für Polartag und Polarnacht
+            let cos_h0 = (-phi.tan() * declination.tan()).clamp(-1.0, 1.0);
+            let h0 = cos_h0.acos();
+            let insolation = flux / std::f64::consts::PI
+                * (h0 * phi.sin() * declination.sin() + phi.cos() * declination.cos() * h0.sin());
+
+            min = min.min(insolation);
+            max = max.max(insolation);
+        }
+        (min, max)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -127,17 +1976,1350 @@ pub struct SerializableBody {
     pub satellites: Vec<SerializableBody>,
 }
 
+/// Fehler beim Lesen oder Schreiben eines Systems in einem Textformat.
+#[derive(Debug)]
+pub enum SystemFormatError {
+    /// Fehler beim Schreiben von RON.
+    Ron(ron::Error),
+    /// Fehler beim Lesen von RON, mit Zeile und Spalte der Fundstelle.
+    RonParse(ron::error::SpannedError),
+    /// Fehler beim Lesen oder Schreiben von JSON.
+    Json(serde_json::Error),
+}
+
+impl std::fmt::Display for SystemFormatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SystemFormatError::Ron(error) => write!(f, "RON-Fehler: {}", error),
+            SystemFormatError::RonParse(error) => write!(f, "RON-Fehler: {}", error),
+            SystemFormatError::Json(error) => write!(f, "JSON-Fehler: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for SystemFormatError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SystemFormatError::Ron(error) => Some(error),
+            SystemFormatError::RonParse(error) => Some(error),
+            SystemFormatError::Json(error) => Some(error),
+        }
+    }
+}
+
+/// Der Zustand aller Körper eines Systems zu einem Zeitpunkt der N-Körper-Integration.
+///
+/// Orte und Geschwindigkeiten sind baryzentrisch und stehen in der Reihenfolge
+/// von [`SerializableStellarSystem::all_bodies`].
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    pub time: Time<Second>,
+    pub positions: Vec<Vec3Distance>,
+    pub velocities: Vec<Vec3Velocity>,
+    pub total_energy: Energy<Joule>,
+}
+
+/// Eine Trümmerscheibe (Kuipergürtel-Analogon) jenseits des äußersten Planeten.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct DebrisDisk {
+    pub inner_edge: Distance<AstronomicalUnit>,
+    pub outer_edge: Distance<AstronomicalUnit>,
+    pub mass: Mass<EarthMass>,
+}
+
+impl DebrisDisk {
+    /// Heutige Masse des Kuipergürtels in Erdmassen.
+    const KUIPER_BELT_MASS: f64 = 0.1;
+    /// Innenrand des Kuipergürtels in AE.
+    const KUIPER_BELT_INNER_EDGE: f64 = 39.0;
+
+    /// Legt eine Scheibe knapp außerhalb der Bahn des äußersten Planeten an.
+    ///
+    /// Die Scheibenmasse skaliert mit der Sternmasse und dem Metallgehalt
+    /// `metallicity` ([Fe/H] in dex): metallreiche Sterne bilden mehr Planetesimale.
+    pub fn beyond(
+        outermost_planet: Distance<AstronomicalUnit>,
+        star: &StarData,
+        metallicity: f64,
+    ) -> Self {
+        let a = outermost_planet.value();
+        Self {
+            inner_edge: Distance::<AstronomicalUnit>::new(1.3 * a),
+            outer_edge: Distance::<AstronomicalUnit>::new(2.0 * a),
+            mass: Mass::<EarthMass>::new(
+                Self::KUIPER_BELT_MASS * star.mass.value() * 10f64.powf(metallicity),
+            ),
+        }
+    }
+
+    /// Einschlagsrisiko für innere Planeten relativ zum Sonnensystem (= 1).
+    ///
+    /// Die Rate der ins Innere gestreuten Körper wächst mit der Scheibenmasse und
+    /// mit der Bahnfrequenz am Innenrand (∝ a^-1.5).
+    pub fn impact_risk_factor(&self) -> f64 {
+        self.mass.value() / Self::KUIPER_BELT_MASS
+            * (Self::KUIPER_BELT_INNER_EDGE / self.inner_edge.value()).powf(1.5)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SerializableStellarSystem {
+    /// Die Version des Dateiformats; ältere Dateien ohne dieses Feld haben 0.
+    #[serde(default)]
+    pub schema_version: u32,
     pub name: String,
     pub age: Time<Gigayear>, // Verwende Time<Gigayear> statt Age(f64)
     pub roots: Vec<SerializableBody>,
+    #[serde(default)]
+    pub debris_disk: Option<DebrisDisk>,
+}
+
+/// Gemeinsame Schnittstelle aller massebehafteten Körper eines Systems
+/// für generische Traversierung (Gesamtmasse, Suchen, ...).
+pub trait Body {
+    /// Die Masse des Körpers.
+    fn mass(&self) -> Mass<Kilogram>;
+    /// Eine stabile, aus dem Namenspfad von der Wurzel abgeleitete Kennung des Körpers.
+    fn body_id(&self) -> u64;
+    /// Der Ort des Körpers zur Zeit `time` relativ zur Wurzel seiner Hierarchie.
+    fn position_at(&self, time: Time<Second>) -> Vec3Distance;
+}
+
+/// Ein Stern oder Planet zusammen mit seiner Lage in der Hierarchie des Systems.
+#[derive(Debug, Clone)]
+pub struct SystemBody<'a> {
+    pub body: &'a SerializableBody,
+    /// FNV-1a-Hash der Namen von der Wurzel bis zu diesem Körper.
+    id: u64,
+    /// Die umlaufenden Körper von der Wurzel bis zu diesem Körper, jeweils mit
+    /// ihrer Bahn und der Gesamtmasse des Zweikörperproblems.
+    orbits: Vec<(&'a SerializableBody, Orbit, Mass<Kilogram>)>,
+}
+
+impl SystemBody<'_> {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+    /// FNV-1a, damit die Kennung über Programmläufe und Versionen hinweg gleich bleibt.
+    fn hash_name(hash: u64, name: &str) -> u64 {
+        // Der Trenner verhindert, dass "A"/"B c" und "A B"/"c" gleich hashen
+        name.bytes()
+            .chain(std::iter::once(0))
+            .fold(hash, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            })
+    }
+
+    /// Die genäherte Bahn von `other` relativ zu diesem Körper.
+    ///
+    /// Ab dem letzten gemeinsamen Vorfahren addieren sich die großen Halbachsen
+    /// beider Bahnketten; das ist exakt für Trabanten, für Vorfahren und für
+    /// Doppelsterne um ein Baryzentrum und überschätzt den Abstand
+    /// zirkumbinärer Planeten um die Bahn des Sterns. `None` für diesen Körper
+    /// selbst und für Körper, die einen anderen Körper als Trabanten begleiten
+    /// (Monde und Planeten anderer Sterne).
+    fn relative_orbit(&self, other: &SystemBody) -> Option<Orbit> {
+        let common = self
+            .orbits
+            .iter()
+            .zip(&other.orbits)
+            .take_while(|(own, others)| std::ptr::eq(own.0, others.0))
+            .count();
+        let (own, others) = (&self.orbits[common..], &other.orbits[common..]);
+        if others.len() > 1 || (own.is_empty() && others.is_empty()) {
+            return None;
+        }
+        let (semi_major_axis, eccentricity) = own
+            .iter()
+            .chain(others)
+            .fold((0.0, 0.0_f64), |(a, e), (_, orbit, _)| {
+                (a + orbit.semi_major_axis.value(), e.max(orbit.eccentricity))
+            });
+        Some(Orbit {
+            semi_major_axis: Distance::<AstronomicalUnit>::new(semi_major_axis),
+            eccentricity,
+            ..Default::default()
+        })
+    }
+}
+
+impl Body for SystemBody<'_> {
+    fn mass(&self) -> Mass<Kilogram> {
+        self.body.mass()
+    }
+
+    fn body_id(&self) -> u64 {
+        self.id
+    }
+
+    fn position_at(&self, time: Time<Second>) -> Vec3Distance {
+        let mut position = [0.0; 3];
+        for (_, orbit, total_mass) in &self.orbits {
+            let (r, _) = orbit.state_vector(time, *total_mass);
+            for k in 0..3 {
+                position[k] += r[k].value();
+            }
+        }
+        position.map(Distance::<Meter>::new)
+    }
+}
+
+impl SerializableBody {
+    /// Die Masse des Körpers; Baryzentren sind masselos.
+    pub fn mass(&self) -> Mass<Kilogram> {
+        match &self.kind {
+            BodyKind::Star(star) => star.mass.convert_to::<Kilogram>(),
+            BodyKind::Planet(planet) => planet.mass.convert_to::<Kilogram>(),
+            BodyKind::Barycenter => Mass::<Kilogram>::new(0.0),
+        }
+    }
+
+    /// Die Gesamtmasse des Zweikörperproblems für die Bahn von `satellite` um
+    /// diesen Körper. Ein Baryzentrum bindet seinen ganzen Teilbaum, ein Körper
+    /// nur sich selbst und den umlaufenden Teilbaum.
+    pub(crate) fn orbit_total_mass(&self, satellite: &SerializableBody) -> Mass<Kilogram> {
+        match self.kind {
+            BodyKind::Barycenter => self.subtree_mass(),
+            _ => self.mass() + satellite.subtree_mass(),
+        }
+    }
+
+    /// Sammelt alle nicht-endlichen Felder dieses Körpers und seiner Satelliten.
+    fn collect_non_finite_fields(&self, errors: &mut Vec<String>) {
+        let mut check = |field: &str, value: f64| {
+            if !value.is_finite() {
+                errors.push(format!(
+                    "{}: {} ist nicht endlich ({})",
+                    self.name, field, value
+                ));
+            }
+        };
+
+        match &self.kind {
+            BodyKind::Star(star) => {
+                check("mass", star.mass.value());
+                check("radius", star.radius.value());
+                check("temperature", star.temperature.value());
+                check("luminosity", star.luminosity.value());
+            }
+            BodyKind::Planet(planet) => {
+                check("mass", planet.mass.value());
+                check("radius", planet.radius.value());
+            }
+            BodyKind::Barycenter => {}
+        }
+
+        if let Some(orbit) = &self.orbit {
+            check("orbit.semi_major_axis", orbit.semi_major_axis.value());
+            check("orbit.eccentricity", orbit.eccentricity);
+            check("orbit.inclination", orbit.inclination.value());
+            check(
+                "orbit.longitude_of_ascending_node",
+                orbit.longitude_of_ascending_node.value(),
+            );
+            check(
+                "orbit.argument_of_periapsis",
+                orbit.argument_of_periapsis.value(),
+            );
+            check(
+                "orbit.mean_anomaly_at_epoch",
+                orbit.mean_anomaly_at_epoch.value(),
+            );
+        }
+
+        for satellite in &self.satellites {
+            satellite.collect_non_finite_fields(errors);
+        }
+    }
+
+    /// Größte Große Halbachse aller Planeten, die direkt einen Stern umkreisen.
+    fn outermost_planet_orbit(&self) -> Option<f64> {
+        let own = match (&self.kind, &self.orbit) {
+            (BodyKind::Planet(_), Some(orbit)) => Some(orbit.semi_major_axis.value()),
+            _ => None,
+        };
+        let satellites = match self.kind {
+            BodyKind::Star(_) | BodyKind::Barycenter => self
+                .satellites
+                .iter()
+                .filter_map(SerializableBody::outermost_planet_orbit)
+                .reduce(f64::max),
+            BodyKind::Planet(_) => None,
+        };
+        own.into_iter().chain(satellites).reduce(f64::max)
+    }
+
+    /// Sucht Bahnresonanzen zwischen benachbarten Satelliten dieses Körpers.
+    ///
+    /// Die Satelliten werden nach großer Halbachse sortiert; für jedes benachbarte
+    /// Paar wird das Periodenverhältnis P_außen/P_innen = (a_außen/a_innen)^(3/2)
+    /// mit allen gekürzten Brüchen p:q bis 5:1 verglichen. Treffer innerhalb von
+    /// 2 % werden als `(innen, außen, p, q)` mit Indizes in `satellites` gemeldet.
+    pub fn detect_resonances(&self) -> Vec<(usize, usize, u32, u32)> {
+        const MAX_ORDER: u32 = 5;
+        const TOLERANCE: f64 = 0.02;
+
+        let mut orbiting: Vec<(usize, f64)> = self
+            .satellites
+            .iter()
+            .enumerate()
+            .filter_map(|(index, satellite)| {
+                satellite
+                    .orbit
+                    .as_ref()
+                    .map(|orbit| (index, orbit.semi_major_axis.value()))
+            })
+            .collect();
+        orbiting.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        let coprime = |mut a: u32, mut b: u32| {
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a == 1
+        };
+
+        orbiting
+            .windows(2)
+            .filter_map(|pair| {
+                let ((inner, a_inner), (outer, a_outer)) = (pair[0], pair[1]);
+                let ratio = (a_outer / a_inner).powf(1.5);
+                (1..=MAX_ORDER)
+                    .flat_map(|q| (q + 1..=MAX_ORDER).map(move |p| (p, q)))
+                    .filter(|&(p, q)| coprime(p, q))
+                    .map(|(p, q)| (p, q, (ratio / (f64::from(p) / f64::from(q)) - 1.0).abs()))
+                    .filter(|&(_, _, deviation)| deviation <= TOLERANCE)
+                    .min_by(|a, b| a.2.total_cmp(&b.2))
+                    .map(|(p, q, _)| (inner, outer, p, q))
+            })
+            .collect()
+    }
+
+    /// Prüft, ob die Ringe eines Planeten stabil sind: Sie liegen oberhalb der
+    /// Planetenoberfläche, innerhalb der Roche-Grenze und enden vor der
+    /// Periapsis des innersten Mondes.
+    pub fn has_stable_rings(&self) -> bool {
+        let BodyKind::Planet(planet) = &self.kind else {
+            return false;
+        };
+        let Some(rings) = planet.rings else {
+            return false;
+        };
+        let inner = rings.inner_radius.convert_to::<Meter>().value();
+        let outer = rings.outer_radius.convert_to::<Meter>().value();
+        let roche = fluid_roche_limit(
+            planet.mass,
+            planet.radius,
+            PlanetData::RING_PARTICLE_DENSITY,
+        )
+        .value();
+        let innermost_moon = self
+            .satellites
+            .iter()
+            .filter_map(|moon| moon.orbit.as_ref())
+            .map(|orbit| {
+                orbit.semi_major_axis.convert_to::<Meter>().value() * (1.0 - orbit.eccentricity)
+            })
+            .fold(f64::INFINITY, f64::min);
+
+        inner > planet.radius.convert_to::<Meter>().value()
+            && inner < outer
+            && outer <= roche * 1.05
+            && outer < innermost_moon
+    }
+
+    /// Die Namen aller Monde, auf die die Ringe im Laufe des Planetenjahres
+    /// einen Schatten werfen können.
+    ///
+    /// Die Ringebene ist um die Achsneigung gegen die Bahnebene gekippt, sodass die
+    /// Sonne im Jahreslauf bis zur Höhe β = Achsneigung über der Ringebene steht.
+    /// Ein um i geneigter Mond im Abstand r liegt bis zu r·sin(i) über der Ringebene;
+    /// sein Schattenstrahl trifft die Ebene um r·sin(i)/tan(β) versetzt. Fällt dieser
+    /// Punkt bei flacher stehender Sonne in den Ring, liegt der Mond zeitweise im Schatten.
+    pub fn ring_shadowed_moons(&self) -> Vec<&str> {
+        let BodyKind::Planet(planet) = &self.kind else {
+            return Vec::new();
+        };
+        let Some(rings) = planet.rings else {
+            return Vec::new();
+        };
+        let max_elevation = planet.obliquity.value().abs();
+        if max_elevation == 0.0 {
+            return Vec::new();
+        }
+        let inner = rings.inner_radius.convert_to::<Meter>().value();
+
+        self.satellites
+            .iter()
+            .filter(|moon| {
+                moon.orbit.as_ref().is_some_and(|orbit| {
+                    let r = orbit.semi_major_axis.convert_to::<Meter>().value();
+                    let height = r * orbit.inclination.value().sin().abs();
+                    height > 0.0 && height / max_elevation.tan() <= r - inner
+                })
+            })
+            .map(|moon| moon.name.as_str())
+            .collect()
+    }
+
+    /// Sammelt die Massenpunkte dieses Teilbaums, ausgehend von Ort und
+    /// Geschwindigkeit des Körpers selbst.
+    ///
+    /// Satelliten werden über ihre Bahn relativ zum Elternkörper platziert; bei
+    /// einem Baryzentrum zählt die Masse seines gesamten Teilbaums.
+    fn collect_point_masses(
+        &self,
+        position: [f64; 3],
+        velocity: [f64; 3],
+        bodies: &mut Vec<PointMass>,
+    ) {
+        if !matches!(self.kind, BodyKind::Barycenter) {
+            bodies.push(PointMass {
+                mass: self.mass().value(),
+                position,
+                velocity,
+            });
+        }
+        for satellite in &self.satellites {
+            let Some(orbit) = &satellite.orbit else {
+                satellite.collect_point_masses(position, velocity, bodies);
+                continue;
+            };
+            let total_mass = self.orbit_total_mass(satellite);
+            let (r, v) = orbit.state_vector(Time::<Second>::new(0.0), total_mass);
+            satellite.collect_point_masses(
+                std::array::from_fn(|k| position[k] + r[k].value()),
+                std::array::from_fn(|k| velocity[k] + v[k].value()),
+                bodies,
+            );
+        }
+    }
+
+    /// Sammelt alle Sterne und Planeten dieses Körpers und seiner Satelliten.
+    ///
+    /// `id` ist der Hash des Namenspfads bis zum Elternkörper, `orbits` die
+    /// Bahnkette von der Wurzel bis zu diesem Körper.
+    fn collect_bodies<'a>(
+        &'a self,
+        id: u64,
+        orbits: Vec<(&'a SerializableBody, Orbit, Mass<Kilogram>)>,
+        bodies: &mut Vec<SystemBody<'a>>,
+    ) {
+        let id = SystemBody::hash_name(id, &self.name);
+        if !matches!(self.kind, BodyKind::Barycenter) {
+            bodies.push(SystemBody {
+                body: self,
+                id,
+                orbits: orbits.clone(),
+            });
+        }
+        for satellite in &self.satellites {
+            let mut orbits = orbits.clone();
+            if let Some(orbit) = satellite.orbit {
+                orbits.push((satellite, orbit, self.orbit_total_mass(satellite)));
+            }
+            satellite.collect_bodies(id, orbits, bodies);
+        }
+    }
+
+    /// Schreibt eine CSV-Zeile für diesen Körper und rekursiv für seine Satelliten.
+    fn write_csv_rows(&self, parent: &str, csv: &mut String) {
+        let escape = |field: &str| {
+            if field.contains([',', '"', '\n']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        };
+        let (kind, luminosity) = match &self.kind {
+            BodyKind::Star(star) => ("star", star.luminosity.value().to_string()),
+            BodyKind::Planet(_) => ("planet", String::new()),
+            BodyKind::Barycenter => ("barycenter", String::new()),
+        };
+        let mass = self.mass().convert_to::<SolarMass>().value();
+        let (semi_major_axis, eccentricity) = match &self.orbit {
+            Some(orbit) => (
+                orbit.semi_major_axis.value().to_string(),
+                orbit.eccentricity.to_string(),
+            ),
+            None => (String::new(), String::new()),
+        };
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            escape(&self.name),
+            escape(parent),
+            kind,
+            mass,
+            semi_major_axis,
+            eccentricity,
+            luminosity
+        ));
+        for satellite in &self.satellites {
+            satellite.write_csv_rows(&self.name, csv);
+        }
+    }
+
+    /// Die Masse dieses Körpers einschließlich aller Satelliten.
+    pub(crate) fn subtree_mass(&self) -> Mass<Kilogram> {
+        self.mass()
+            + self
+                .satellites
+                .iter()
+                .map(SerializableBody::subtree_mass)
+                .sum()
+    }
+
+    /// Schreibt Knoten und Kanten dieses Teilbaums im DOT-Format; gibt die Kennung
+    /// des eigenen Knotens zurück.
+    fn write_dot(&self, next_id: &mut usize, dot: &mut String) -> String {
+        let id = format!("n{}", *next_id);
+        *next_id += 1;
+
+        let name = self.name.replace('"', "\\\"");
+        let label = match &self.kind {
+            BodyKind::Star(star) => format!(
+                "{}\\n{}\\n{:.2} M☉",
+                name,
+                star.spectral_classification(),
+                star.mass.value()
+            ),
+            BodyKind::Planet(planet) => format!("{}\\n{:.2} M⊕", name, planet.mass.value()),
+            BodyKind::Barycenter => format!("{}\\nBaryzentrum", name),
+        };
+        let shape = match self.kind {
+            BodyKind::Star(_) => "doublecircle",
+            BodyKind::Planet(_) => "circle",
+            BodyKind::Barycenter => "point",
+        };
+        dot.push_str(&format!(
+            "    {} [label=\"{}\", shape={}];\n",
+            id, label, shape
+        ));
+
+        for satellite in &self.satellites {
+            let child = satellite.write_dot(next_id, dot);
+            match &satellite.orbit {
+                Some(orbit) => {
+                    let period = orbit
+                        .orbital_period(self.orbit_total_mass(satellite))
+                        .convert_to::<Day>();
+                    dot.push_str(&format!(
+                        "    {} -> {} [label=\"P = {:.1} d\\ne = {:.2}\"];\n",
+                        id,
+                        child,
+                        period.value(),
+                        orbit.eccentricity
+                    ));
+                }
+                None => dot.push_str(&format!("    {} -> {};\n", id, child)),
+            }
+        }
+        id
+    }
+
+    fn collect_planets<'a>(
+        &'a self,
+        host: Option<&'a StarData>,
+        planets: &mut Vec<(&'a StarData, &'a PlanetData, &'a Orbit)>,
+    ) {
+        let host = match &self.kind {
+            BodyKind::Star(star) => Some(star),
+            _ => host,
+        };
+        for satellite in &self.satellites {
+            if let (Some(star), BodyKind::Planet(planet), Some(orbit)) =
+                (host, &satellite.kind, &satellite.orbit)
+            {
+                planets.push((star, planet, orbit));
+            }
+            satellite.collect_planets(host, planets);
+        }
+    }
+
+    /// Sammelt alle Paare aus einem Stern und einem stellaren Trabanten, die im
+    /// Systemalter `age` noch durch Gravitationswellen verschmelzen (Tiefensuche).
+    fn collect_merging_pairs(
+        &self,
+        age: Time<Gigayear>,
+        pairs: &mut Vec<(String, String, Time<Gigayear>)>,
+    ) {
+        for satellite in &self.satellites {
+            let inspiral = match (&self.kind, &satellite.kind, &satellite.orbit) {
+                (BodyKind::Star(primary), BodyKind::Star(secondary), Some(orbit)) => {
+                    orbit.merger_time(primary, secondary, age)
+                }
+                _ => None,
+            };
+            if let Some(inspiral) = inspiral {
+                pairs.push((self.name.clone(), satellite.name.clone(), inspiral));
+            }
+            satellite.collect_merging_pairs(age, pairs);
+        }
+    }
+
+    fn collect_stars<'a>(&'a self, stars: &mut Vec<&'a StarData>) {
+        if let BodyKind::Star(star) = &self.kind {
+            stars.push(star);
+        }
+        for satellite in &self.satellites {
+            satellite.collect_stars(stars);
+        }
+    }
+}
+
+impl SerializableStellarSystem {
+    /// Die aktuelle Version des Dateiformats. Neue Felder erhalten
+    /// `#[serde(default)]`, damit Dateien älterer Versionen lesbar bleiben.
+    pub const SCHEMA_VERSION: u32 = 1;
+
+    /// Prüft, ob alle physikalischen Felder des Systems endliche Werte enthalten.
+    ///
+    /// Gibt bei Fehlern eine Liste aller betroffenen Felder zurück.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        if !self.age.value().is_finite() {
+            errors.push(format!(
+                "{}: age ist nicht endlich ({})",
+                self.name,
+                self.age.value()
+            ));
+        }
+        for root in &self.roots {
+            root.collect_non_finite_fields(&mut errors);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Alle Sterne des Systems in Baumreihenfolge.
+    pub fn stars(&self) -> Vec<&StarData> {
+        let mut stars = Vec::new();
+        for root in &self.roots {
+            root.collect_stars(&mut stars);
+        }
+        stars
+    }
+
+    /// Alle Sterne und Planeten (einschließlich Monde) in Baumreihenfolge;
+    /// Baryzentren werden übersprungen.
+    pub fn all_bodies(&self) -> Vec<SystemBody<'_>> {
+        let mut bodies = Vec::new();
+        for root in &self.roots {
+            root.collect_bodies(SystemBody::FNV_OFFSET_BASIS, Vec::new(), &mut bodies);
+        }
+        bodies
+    }
+
+    /// Die Massenpunkte aller Sterne und Planeten im Schwerpunktsystem, mit
+    /// Anfangswerten aus den Bahnelementen der Hierarchie.
+    fn point_masses(&self) -> Vec<PointMass> {
+        let mut bodies = Vec::new();
+        for root in &self.roots {
+            root.collect_point_masses([0.0; 3], [0.0; 3], &mut bodies);
+        }
+
+        let total_mass: f64 = bodies.iter().map(|body| body.mass).sum();
+        if total_mass > 0.0 {
+            let center: [[f64; 3]; 2] = [
+                std::array::from_fn(|k| {
+                    bodies.iter().map(|b| b.mass * b.position[k]).sum::<f64>() / total_mass
+                }),
+                std::array::from_fn(|k| {
+                    bodies.iter().map(|b| b.mass * b.velocity[k]).sum::<f64>() / total_mass
+                }),
+            ];
+            for body in &mut bodies {
+                for (position, offset) in body.position.iter_mut().zip(center[0]) {
+                    *position -= offset;
+                }
+                for (velocity, offset) in body.velocity.iter_mut().zip(center[1]) {
+                    *velocity -= offset;
+                }
+            }
+        }
+        bodies
+    }
+
+    /// Die Newtonsche Gesamtenergie (kinetisch plus potentiell) aller Körper,
+    /// berechnet aus Bahnelementen und Massen; gebundene Systeme sind negativ.
+    pub fn total_energy(&self) -> Energy<Joule> {
+        Energy::<Joule>::new(total_energy(&self.point_masses()))
+    }
+
+    /// Der Betrag des Gesamtdrehimpulses aller Körper um den Schwerpunkt.
+    pub fn total_angular_momentum(&self) -> AngularMomentum<KilogramSquareMeterPerSecond> {
+        let [x, y, z] = total_angular_momentum(&self.point_masses());
+        AngularMomentum::<KilogramSquareMeterPerSecond>::new((x * x + y * y + z * z).sqrt())
+    }
+
+    /// Integriert die Bahnen aller Körper mit Newtonscher Gravitation über
+    /// `steps` Leapfrog-Schritte der Länge `dt`.
+    ///
+    /// Die Anfangswerte stammen aus den Bahnelementen der Hierarchie und werden
+    /// in das Schwerpunktsystem verschoben. Das Ergebnis enthält den
+    /// Anfangszustand und einen [`Snapshot`] nach jedem Schritt; die Erhaltung
+    /// von `total_energy` dient als Kontrolle der Schrittweite.
+    pub fn integrate<T>(&self, steps: usize, dt: Time<T>) -> Vec<Snapshot>
+    where
+        Time<T>: ToSI,
+    {
+        let mut bodies = self.point_masses();
+        let dt = dt.to_si();
+        let snapshot = |step: usize, bodies: &[PointMass]| Snapshot {
+            time: Time::<Second>::new(step as f64 * dt),
+            positions: bodies
+                .iter()
+                .map(|body| body.position.map(Distance::<Meter>::new))
+                .collect(),
+            velocities: bodies
+                .iter()
+                .map(|body| body.velocity.map(Velocity::<MeterPerSecond>::new))
+                .collect(),
+            total_energy: Energy::<Joule>::new(total_energy(bodies)),
+        };
+
+        let mut snapshots = Vec::with_capacity(steps + 1);
+        snapshots.push(snapshot(0, &bodies));
+        for step in 1..=steps {
+            leapfrog_step(&mut bodies, dt);
+            snapshots.push(snapshot(step, &bodies));
+        }
+        snapshots
+    }
+
+    /// Katalogartige Klassifikation eines Doppelsternsystems, z.B. "G2V + M4V binary".
+    ///
+    /// Gibt `None` zurück, wenn das System nicht aus genau zwei Sternen besteht.
+    pub fn classify_binary(&self) -> Option<String> {
+        let mut stars = self.stars();
+        if stars.len() != 2 {
+            return None;
+        }
+        stars.sort_by(|a, b| b.mass.value().total_cmp(&a.mass.value()));
+        let (primary, secondary) = (stars[0], stars[1]);
+
+        let label = if primary.is_white_dwarf() || secondary.is_white_dwarf() {
+            format!(
+                "{} + {} pair",
+                primary.pair_description(),
+                secondary.pair_description()
+            )
+        } else if primary.is_giant() != secondary.is_giant() {
+            let (giant, dwarf) = if primary.is_giant() {
+                (primary, secondary)
+            } else {
+                (secondary, primary)
+            };
+            format!(
+                "{} giant + {} main-sequence binary",
+                giant.spectral_classification(),
+                dwarf.spectral_classification()
+            )
+        } else {
+            format!(
+                "{} + {} binary",
+                primary.spectral_classification(),
+                secondary.spectral_classification()
+            )
+        };
+        Some(label)
+    }
+
+    /// Sternpaare, die durch Gravitationswellen verschmelzen, solange das System
+    /// besteht, mit Namen beider Sterne und Verschmelzungszeit.
+    ///
+    /// Betrachtet werden Sterne, die einen anderen Stern als Trabanten umkreisen.
+    /// Die verbleibende Lebensdauer ist die kürzeste Hauptreihen-Lebensdauer der
+    /// beiden Sterne abzüglich des Systemalters; Paare kompakter Überreste
+    /// bestehen 1000 Gyr.
+    pub fn merging_binaries(&self) -> Vec<(String, String, Time<Gigayear>)> {
+        let mut pairs = Vec::new();
+        for root in &self.roots {
+            root.collect_merging_pairs(self.age, &mut pairs);
+        }
+        pairs
+    }
+
+    /// Die normierte Lichtkurve des Sterns über einen Umlauf des Planeten
+    /// `planet_index` (in Durchlaufreihenfolge), mit `samples` Stützstellen ab der
+    /// Transitmitte.
+    ///
+    /// Der Beobachter liegt in der Referenzebene, sodass eine Bahn mit Neigung 0
+    /// zentral vorüberzieht; der Stoßparameter ist b = a · sin(i) / R_s. Die Bahn
+    /// wird als kreisförmig genähert. Ein- und Austritt verlaufen trapezförmig
+    /// linear, `limb_darkening` ist ein optionaler linearer Randverdunkelungs-
+    /// koeffizient u mit I(μ) ∝ 1 − u·(1 − μ). Ohne passenden Planeten ist das
+    /// Ergebnis leer.
+    pub fn transit_light_curve(
+        &self,
+        planet_index: usize,
+        samples: usize,
+        limb_darkening: Option<f64>,
+    ) -> Vec<(Time<Day>, f64)> {
+        let mut planets = Vec::new();
+        for root in &self.roots {
+            root.collect_planets(None, &mut planets);
+        }
+        let Some(&(star, planet, orbit)) = planets.get(planet_index) else {
+            return Vec::new();
+        };
+
+        let star_radius = star.radius.convert_to::<Meter>().value();
+        let k = planet.radius.convert_to::<Meter>().value() / star_radius;
+        let depth = transit_depth(star.radius, planet.radius);
+        let a = orbit.semi_major_axis.convert_to::<Meter>().value() / star_radius;
+        let sin_i = orbit.inclination.value().sin();
+        let u = limb_darkening.unwrap_or(0.0);
+        let total_mass = star.mass.convert_to::<Kilogram>() + planet.mass.convert_to::<Kilogram>();
+        let period = orbit.orbital_period(total_mass).convert_to::<Day>();
+
+        (0..samples)
+            .map(|i| {
+                let phase = 2.0 * std::f64::consts::PI * i as f64 / samples as f64;
+                // Projizierter Abstand der Mittelpunkte in Sternradien
+                let z = a * (phase.sin().powi(2) + (sin_i * phase.cos()).powi(2)).sqrt();
+                let covered = if phase.cos() <= 0.0 || z >= 1.0 + k {
+                    0.0
+                } else if z <= 1.0 - k {
+                    1.0
+                } else {
+                    (1.0 + k - z) / (2.0 * k)
+                };
+                let mu = (1.0 - z.min(1.0).powi(2)).sqrt();
+                let intensity = (1.0 - u * (1.0 - mu)) / (1.0 - u / 3.0);
+                (
+                    period * (i as f64 / samples as f64),
+                    1.0 - depth * covered * intensity,
+                )
+            })
+            .collect()
+    }
+
+    /// Erzeugt bis zu `count` Bahnen von Kleinkörpern eines Asteroidengürtels
+    /// zwischen `inner` und `outer` um den massereichsten Stern; für
+    /// `inner > outer` ist das Ergebnis leer.
+    ///
+    /// Exzentrizitäten und Neigungen sind Rayleigh-verteilt (σ_e = 0.1, σ_i = 5°)
+    /// wie im Hauptgürtel. Geprüft werden alle Sterne und Planeten außer Monden
+    /// mit ihrer Bahn relativ zum Zentralstern, auch zirkumbinäre Planeten und
+    /// Begleiter an anderer Stelle der Hierarchie. Verworfen werden Bahnen, die
+    /// sich der Bahn eines Planeten auf weniger als drei Hill-Radien nähern, die
+    /// in einer Resonanz niedriger Ordnung liegen (Kirkwood-Lücken) oder die für
+    /// einen stellaren Begleiter außerhalb der S- bzw. P-Typ-Stabilitätsgrenzen
+    /// von Holman & Wiegert (1999) liegen. Ein Begleiter, der nach
+    /// [`Self::merging_binaries`] mit dem Stern verschmilzt, zählt zu dessen Masse
+    /// und begrenzt die Bahnen nicht. Bleibt zu wenig stabiler Raum, enthält das
+    /// Ergebnis weniger als `count` Bahnen.
+    pub fn generate_asteroid_belt<R: Rng + ?Sized>(
+        &self,
+        inner: Distance<AstronomicalUnit>,
+        outer: Distance<AstronomicalUnit>,
+        count: usize,
+        rng: &mut R,
+    ) -> Vec<Orbit> {
+        const HILL_RADII: f64 = 3.0;
+        const MAX_ORDER: u32 = 5;
+        const RESONANCE_TOLERANCE: f64 = 0.02;
+        const MAX_ATTEMPTS_PER_BODY: usize = 100;
+
+        if inner.value() > outer.value() {
+            return Vec::new();
+        }
+        let bodies = self.all_bodies();
+        let Some((host, host_star)) = bodies
+            .iter()
+            .filter_map(|body| match &body.body.kind {
+                BodyKind::Star(star) => Some((body, star)),
+                _ => None,
+            })
+            .max_by(|a, b| a.1.mass.value().total_cmp(&b.1.mass.value()))
+        else {
+            return Vec::new();
+        };
+        // Alle übrigen Körper mit ihrer Bahn um den Zentralstern; Begleiter, die
+        // noch zu Lebzeiten des Systems verschmelzen, zählen zur Zentralmasse
+        // statt die Bahnen als Doppelstern zu begrenzen
+        let mut host_mass = host.mass().value();
+        let mut neighbours = Vec::new();
+        for body in &bodies {
+            let Some(orbit) = host.relative_orbit(body) else {
+                continue;
+            };
+            let merger = match &body.body.kind {
+                BodyKind::Star(star) => orbit.merger_time(host_star, star, self.age),
+                _ => None,
+            };
+            match merger {
+                Some(_) => host_mass += body.mass().value(),
+                None => neighbours.push((body, orbit)),
+            }
+        }
+        let host_solar_masses = host_mass / KG_PER_SOLAR_MASS;
+
+        let resonances: Vec<f64> = (1..=MAX_ORDER)
+            .flat_map(|q| (q + 1..=MAX_ORDER).map(move |p| f64::from(p) / f64::from(q)))
+            .collect();
+        let is_stable = |candidate: &Orbit| {
+            let a = candidate.semi_major_axis.value();
+            let periapsis = a * (1.0 - candidate.eccentricity);
+            let apoapsis = a * (1.0 + candidate.eccentricity);
+            neighbours.iter().all(|(body, orbit)| {
+                let (a_p, e_p) = (orbit.semi_major_axis.value(), orbit.eccentricity);
+                match body.body.kind {
+                    BodyKind::Star(_) => {
+                        let mu = body.mass().value() / (host_mass + body.mass().value());
+                        let s_type = a_p
+                            * (0.464 - 0.380 * mu - 0.631 * e_p
+                                + 0.586 * mu * e_p
+                                + 0.150 * e_p * e_p
+                                - 0.198 * mu * e_p * e_p);
+                        let p_type = a_p
+                            * (1.60 + 5.10 * e_p - 2.22 * e_p * e_p + 4.12 * mu
+                                - 4.27 * e_p * mu
+                                - 5.09 * mu * mu
+                                + 4.61 * e_p * e_p * mu * mu);
+                        apoapsis < s_type || periapsis > p_type
+                    }
+                    _ => {
+                        let hill =
+                            a_p * (1.0 - e_p) * (body.mass().value() / (3.0 * host_mass)).cbrt();
+                        let clear_of_orbit = apoapsis < a_p * (1.0 - e_p) - HILL_RADII * hill
+                            || periapsis > a_p * (1.0 + e_p) + HILL_RADII * hill;
+                        let period_ratio = (a.max(a_p) / a.min(a_p)).powf(1.5);
+                        clear_of_orbit
+                            && resonances.iter().all(|&resonance| {
+                                (period_ratio / resonance - 1.0).abs() > RESONANCE_TOLERANCE
+                            })
+                    }
+                }
+            })
+        };
+
+        let eccentricities = EccentricityDistribution::Rayleigh { sigma: 0.1 };
+        let inclinations = InclinationModel::Rayleigh { sigma_deg: 5.0 };
+        let tau = 2.0 * std::f64::consts::PI;
+        let mut belt = Vec::with_capacity(count);
+        for _ in 0..count * MAX_ATTEMPTS_PER_BODY {
+            if belt.len() == count {
+                break;
+            }
+            let semi_major_axis = rng.gen_range(inner.value()..=outer.value());
+            let period = Time::<Year>::new((semi_major_axis.powi(3) / host_solar_masses).sqrt());
+            let candidate = inclinations.orient(
+                Orbit {
+                    semi_major_axis: Distance::<AstronomicalUnit>::new(semi_major_axis),
+                    eccentricity: eccentricities.sample(period, rng),
+                    argument_of_periapsis: Angle::<Radian>::new(rng.gen_range(0.0..tau)),
+                    mean_anomaly_at_epoch: Angle::<Radian>::new(rng.gen_range(0.0..tau)),
+                    ..Default::default()
+                },
+                rng,
+            );
+            if is_stable(&candidate) {
+                belt.push(candidate);
+            }
+        }
+        belt
+    }
+
+    /// Legt eine Trümmerscheibe jenseits des äußersten Planeten an.
+    ///
+    /// Ohne Planeten wird die Scheibe an einem Kuipergürtel-Analogon bei
+    /// 30 AE · √(L/L☉) platziert. Systeme ohne Stern erhalten keine Scheibe.
+    pub fn place_debris_disk(&mut self, metallicity: f64) {
+        let Some(&primary) = self
+            .stars()
+            .iter()
+            .max_by(|a, b| a.mass.value().total_cmp(&b.mass.value()))
+        else {
+            self.debris_disk = None;
+            return;
+        };
+        let outermost = self
+            .roots
+            .iter()
+            .filter_map(SerializableBody::outermost_planet_orbit)
+            .reduce(f64::max)
+            .unwrap_or_else(|| 30.0 * primary.luminosity.value().sqrt());
+        self.debris_disk = Some(DebrisDisk::beyond(
+            Distance::<AstronomicalUnit>::new(outermost),
+            primary,
+            metallicity,
+        ));
+    }
+
+    /// Die Hierarchie als GraphViz-Digraph.
+    ///
+    /// Jeder Körper wird ein Knoten (Sterne mit Spektraltyp und Masse), jede Bahn
+    /// eine Kante vom Zentralkörper zum Begleiter mit Umlaufperiode und
+    /// Exzentrizität. Die Periode wird aus der Gesamtmasse des Teilbaums des
+    /// Zentralkörpers berechnet.
+    pub fn to_dot(&self) -> String {
+        let mut dot = format!("digraph \"{}\" {{\n", self.name.replace('"', "\\\""));
+        let mut next_id = 0;
+        for root in &self.roots {
+            root.write_dot(&mut next_id, &mut dot);
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Eine Tabelle aller Körper als CSV, eine Zeile pro Körper.
+    ///
+    /// Die Hierarchie wird über die Spalte `parent` abgeflacht. Massen sind in M☉,
+    /// große Halbachsen in AE und Leuchtkräfte in L☉ angegeben; Felder ohne Wert
+    /// (Leuchtkraft eines Planeten, Bahn eines Wurzelkörpers) bleiben leer.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from(
+            "name,parent,kind,mass_solar,semi_major_axis_au,eccentricity,luminosity_solar\n",
+        );
+        for root in &self.roots {
+            root.write_csv_rows("", &mut csv);
+        }
+        csv
+    }
+
+    /// Größe der kompakten RON-Darstellung des Systems in Bytes.
+    ///
+    /// Dient als Abschätzung des Speicherbedarfs großer generierter Populationen;
+    /// scheitert wie [`Self::to_ron_string`].
+    pub fn serialized_size(&self) -> Result<usize, SystemFormatError> {
+        self.to_ron_string(false).map(|ron_string| ron_string.len())
+    }
+
+    /// Serialisiert das System nach RON, eingerückt oder kompakt.
+    pub fn to_ron_string(&self, pretty: bool) -> Result<String, SystemFormatError> {
+        let result = if pretty {
+            ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::new())
+        } else {
+            ron::to_string(self)
+        };
+        result.map_err(SystemFormatError::Ron)
+    }
+
+    /// Liest ein System aus einem RON-String.
+    pub fn from_ron_string(input: &str) -> Result<Self, SystemFormatError> {
+        ron::from_str(input).map_err(SystemFormatError::RonParse)
+    }
+
+    /// Serialisiert das System nach JSON, eingerückt oder kompakt.
+    ///
+    /// Nicht-endliche Werte werden als `null` geschrieben.
+    pub fn to_json_string(&self, pretty: bool) -> Result<String, SystemFormatError> {
+        let result = if pretty {
+            serde_json::to_string_pretty(self)
+        } else {
+            serde_json::to_string(self)
+        };
+        result.map_err(SystemFormatError::Json)
+    }
+
+    /// Liest ein System aus einem JSON-String.
+    pub fn from_json_string(input: &str) -> Result<Self, SystemFormatError> {
+        serde_json::from_str(input).map_err(SystemFormatError::Json)
+    }
+
+    /// Serialisiert das System in das kompakte Binärformat von `bincode`.
+    #[cfg(feature = "bincode")]
+    pub fn to_bincode(&self) -> Result<Vec<u8>, bincode::Error> {
+        bincode::serialize(self)
+    }
+
+    /// Liest ein mit [`Self::to_bincode`] geschriebenes System wieder ein.
+    #[cfg(feature = "bincode")]
+    pub fn from_bincode(bytes: &[u8]) -> Result<Self, bincode::Error> {
+        bincode::deserialize(bytes)
+    }
+
+    /// Die kompakte Binärdarstellung für Kataloge vieler Systeme.
+    ///
+    /// Alle Felder haben eine feste Struktur, daher kann `bincode` hier nicht scheitern.
+    #[cfg(feature = "bincode")]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.to_bincode()
+            .expect("Sternensysteme sind immer bincode-serialisierbar")
+    }
+
+    /// Liest ein mit [`Self::to_bytes`] geschriebenes System wieder ein.
+    #[cfg(feature = "bincode")]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, bincode::Error> {
+        Self::from_bincode(bytes)
+    }
 }
 
 //================================================================================
 // 5. Generierungslogik (angepasst an die neuen Typen)
 //================================================================================
 
+/// Verteilung, aus der die Exzentrizitäten generierter Doppelstern- und
+/// Planetenbahnen gezogen werden.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum EccentricityDistribution {
+    /// Gleichverteilt zwischen 0.0 und 0.8.
+    Uniform,
+    /// Thermische Verteilung f(e) = 2e, wie sie für weite Doppelsterne beobachtet wird.
+    Thermal,
+    /// Rayleigh-Verteilung, typisch für Planetensysteme mit kleinem `sigma`.
+    Rayleigh { sigma: f64 },
+    /// Bahnen mit kürzerer Periode sind durch Gezeiten zirkularisiert,
+    /// längere folgen der thermischen Verteilung.
+    CircularizedBelow { period: Time<Day> },
+}
+
+impl EccentricityDistribution {
+    /// Obergrenze, damit gezogene Bahnen gebunden bleiben.
+    const MAX_ECCENTRICITY: f64 = 0.99;
+
+    /// Zieht eine Exzentrizität für eine Bahn mit der gegebenen Umlaufperiode.
+    pub fn sample<U, R: Rng + ?Sized>(&self, period: Time<U>, rng: &mut R) -> f64
+    where
+        Time<U>: ToSI,
+    {
+        let e = match *self {
+            EccentricityDistribution::Uniform => rng.gen_range(0.0..0.8),
+            EccentricityDistribution::Thermal => rng.r#gen::<f64>().sqrt(),
+            EccentricityDistribution::Rayleigh { sigma } => {
+                sigma * (-2.0 * (1.0 - rng.r#gen::<f64>()).ln()).sqrt()
+            }
+            EccentricityDistribution::CircularizedBelow { period: cutoff } => {
+                if period.to_si() < cutoff.to_si() {
+                    0.0
+                } else {
+                    rng.r#gen::<f64>().sqrt()
+                }
+            }
+        };
+        e.min(Self::MAX_ECCENTRICITY)
+    }
+}
+
+/// Verteilung der Bahnneigungen in generierten Mehrfachsystemen.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum InclinationModel {
+    /// Alle Bahnen liegen in derselben Ebene.
+    Coplanar,
+    /// Bahnebenen zufällig im Raum orientiert (cos i gleichverteilt).
+    IsotropicRandom,
+    /// Rayleigh-verteilte Neigung um die Referenzebene, `sigma_deg` in Grad.
+    Rayleigh { sigma_deg: f64 },
+}
+
+impl InclinationModel {
+    /// Zieht eine Bahnneigung gegenüber der Referenzebene.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Angle<Radian> {
+        match *self {
+            InclinationModel::Coplanar => Angle::<Radian>::new(0.0),
+            InclinationModel::IsotropicRandom => {
+                Angle::<Radian>::new(rng.gen_range(-1.0..=1.0_f64).acos())
+            }
+            InclinationModel::Rayleigh { sigma_deg } => {
+                let i = sigma_deg * (-2.0 * (1.0 - rng.r#gen::<f64>()).ln()).sqrt();
+                Angle::<Degree>::new(i.min(180.0)).convert_to::<Radian>()
+            }
+        }
+    }
+
+    /// Orientiert eine Bahn neu: Neigung aus dem Modell, aufsteigender Knoten
+    /// gleichverteilt. Koplanare Bahnen behalten ihren Knoten.
+    pub fn orient<R: Rng + ?Sized>(&self, orbit: Orbit, rng: &mut R) -> Orbit {
+        match self {
+            InclinationModel::Coplanar => Orbit {
+                inclination: Angle::<Radian>::new(0.0),
+                ..orbit
+            },
+            _ => Orbit {
+                inclination: self.sample(rng),
+                longitude_of_ascending_node: Angle::<Radian>::new(
+                    rng.gen_range(0.0..2.0 * std::f64::consts::PI),
+                ),
+                ..orbit
+            },
+        }
+    }
+}
+
+/// Anordnung der Bahnen in einem generierten Planetensystem.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PlanetSpacing {
+    /// Jede Halbachse ist um einen festen Faktor größer als die vorherige.
+    Geometric { ratio: f64 },
+    /// Aufeinanderfolgende Planeten liegen in exakten Bahnresonanzen.
+    /// Ein Eintrag `(p, q)` bedeutet P_außen / P_innen = p / q, z.B. `(3, 2)`.
+    ResonanceChain(Vec<(u32, u32)>),
+}
+
+impl PlanetSpacing {
+    /// Berechnet die großen Halbachsen von bis zu `count` Planeten, beginnend beim
+    /// innersten Planeten. Eine Resonanzkette liefert höchstens einen Planeten
+    /// mehr als sie Resonanzen enthält.
+    pub fn semi_major_axes(
+        &self,
+        innermost: Distance<AstronomicalUnit>,
+        count: usize,
+    ) -> Vec<Distance<AstronomicalUnit>> {
+        let factors: Vec<f64> = match self {
+            PlanetSpacing::Geometric { ratio } => vec![*ratio; count.saturating_sub(1)],
+            // Drittes Keplersches Gesetz: a ∝ P^(2/3) bei gleicher Zentralmasse
+            PlanetSpacing::ResonanceChain(ratios) => ratios
+                .iter()
+                .take(count.saturating_sub(1))
+                .map(|&(p, q)| (f64::from(p) / f64::from(q)).powf(2.0 / 3.0))
+                .collect(),
+        };
+
+        let mut axes = Vec::with_capacity(factors.len() + 1);
+        if count == 0 {
+            return axes;
+        }
+        axes.push(innermost);
+        for factor in factors {
+            let previous = axes[axes.len() - 1];
+            axes.push(previous * factor);
+        }
+        axes
+    }
+}
+
+/// Anfangsmassenfunktion für die Massen neu entstehender Sterne.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum InitialMassFunction {
+    /// Einfaches Potenzgesetz ξ(m) ∝ m^-2.35 (Salpeter 1955).
+    Salpeter,
+    /// Gebrochenes Potenzgesetz mit α = 0.3 / 1.3 / 2.3 und Knicken bei 0.08 und
+    /// 0.5 M☉ (Kroupa 2001).
+    Kroupa,
+    /// Log-Normalverteilung unter 1 M☉ (m_c = 0.079 M☉, σ = 0.69) mit
+    /// Salpeter-artigem Ausläufer m^-2.3 darüber (Chabrier 2003).
+    Chabrier,
+}
+
+impl InitialMassFunction {
+    /// Stützstellen der tabellierten Verteilungsfunktion.
+    const CDF_SAMPLES: usize = 512;
+
+    /// Die unnormierte Anzahldichte dN/dm bei der Masse `mass` (in M☉).
+    fn density(&self, mass: f64) -> f64 {
+        match *self {
+            InitialMassFunction::Salpeter => mass.powf(-2.35),
+            InitialMassFunction::Kroupa => {
+                if mass < 0.08 {
+                    (mass / 0.08).powf(-0.3) * (0.08_f64 / 0.5).powf(-1.3)
+                } else if mass < 0.5 {
+                    (mass / 0.5).powf(-1.3)
+                } else {
+                    (mass / 0.5).powf(-2.3)
+                }
+            }
+            InitialMassFunction::Chabrier => {
+                let log_normal =
+                    |m: f64| (-(m / 0.079).log10().powi(2) / (2.0 * 0.69_f64.powi(2))).exp() / m;
+                if mass <= 1.0 {
+                    log_normal(mass)
+                } else {
+                    log_normal(1.0) * mass.powf(-2.3)
+                }
+            }
+        }
+    }
+
+    /// Zieht eine Sternmasse zwischen `min_mass` und `max_mass`.
+    ///
+    /// Die Verteilungsfunktion wird in ln m tabelliert und per Inversion
+    /// gezogen, sodass alle drei Formen gleich behandelt werden.
+    pub fn sample<R: Rng + ?Sized>(
+        &self,
+        min_mass: Mass<SolarMass>,
+        max_mass: Mass<SolarMass>,
+        rng: &mut R,
+    ) -> Mass<SolarMass> {
+        let (low, high) = (min_mass.value().ln(), max_mass.value().ln());
+        let step = (high - low) / Self::CDF_SAMPLES as f64;
+        // dN/d ln m = m · ξ(m)
+        let per_log_mass = |i: usize| {
+            let mass = (low + step * i as f64).exp();
+            mass * self.density(mass)
+        };
+
+        let mut cdf = Vec::with_capacity(Self::CDF_SAMPLES + 1);
+        cdf.push(0.0);
+        for i in 1..=Self::CDF_SAMPLES {
+            let area = 0.5 * step * (per_log_mass(i - 1) + per_log_mass(i));
+            cdf.push(cdf[i - 1] + area);
+        }
+
+        let target = rng.r#gen::<f64>() * cdf[Self::CDF_SAMPLES];
+        let upper = cdf
+            .partition_point(|&value| value < target)
+            .clamp(1, Self::CDF_SAMPLES);
+        let fraction = (target - cdf[upper - 1]) / (cdf[upper] - cdf[upper - 1]);
+        Mass::<SolarMass>::new((low + step * (upper as f64 - 1.0 + fraction)).exp())
+    }
+}
+
+/// Beobachtete Verteilungen von Massenverhältnis und Periode in Doppelsternen.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BinaryDistribution {
+    /// Exponent γ des Potenzgesetzes f(q) ∝ q^γ für q = M₂/M₁.
+    pub mass_ratio_exponent: f64,
+    /// Das kleinste gezogene Massenverhältnis.
+    pub min_mass_ratio: f64,
+    /// Mittelwert der Log-Normalverteilung von log₁₀(P / Tag).
+    pub log_period_mean: f64,
+    /// Standardabweichung von log₁₀(P / Tag).
+    pub log_period_sigma: f64,
+}
+
+impl BinaryDistribution {
+    /// Genäherte Parameter für einen Hauptstern der Masse `primary_mass`.
+    ///
+    /// Die Massenverhältnisse folgen Duchêne & Kraus (2013): M-Zwerge und
+    /// sonnenähnliche Sterne bevorzugen leicht gleich schwere Begleiter, A- und
+    /// B-Sterne leichte. Die Perioden sind log-normal, für sonnenähnliche Sterne
+    /// nach Raghavan et al. (2010) mit log P = 5.03 ± 2.28; massereiche Sterne
+    /// haben deutlich engere Begleiter.
+    pub fn for_primary_mass<U>(primary_mass: Mass<U>) -> Self
+    where
+        Mass<U>: ToSI,
+    {
+        let mass = primary_mass.convert_to::<SolarMass>().value();
+        let (mass_ratio_exponent, log_period_mean, log_period_sigma) = match mass {
+            m if m < 0.6 => (0.4, 4.4, 1.5),
+            m if m < 1.5 => (0.3, 5.03, 2.28),
+            m if m < 8.0 => (-0.5, 6.2, 1.5),
+            _ => (-0.1, 1.5, 1.5),
+        };
+        BinaryDistribution {
+            mass_ratio_exponent,
+            min_mass_ratio: 0.1,
+            log_period_mean,
+            log_period_sigma,
+        }
+    }
+
+    /// Zieht ein Massenverhältnis q = M₂/M₁ zwischen `min_mass_ratio` und 1.
+    pub fn sample_mass_ratio<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let u = rng.r#gen::<f64>();
+        let exponent = self.mass_ratio_exponent + 1.0;
+        if exponent.abs() < 1e-9 {
+            return self.min_mass_ratio.powf(1.0 - u);
+        }
+        let low = self.min_mass_ratio.powf(exponent);
+        (low + u * (1.0 - low)).powf(1.0 / exponent)
+    }
+
+    /// Zieht eine Umlaufperiode aus der Log-Normalverteilung.
+    pub fn sample_period<R: Rng + ?Sized>(&self, rng: &mut R) -> Time<Day> {
+        // Box-Muller-Transformation für eine Standardnormalverteilung
+        let u1 = 1.0 - rng.r#gen::<f64>();
+        let u2 = rng.r#gen::<f64>();
+        let normal = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+        Time::<Day>::new(10f64.powf(self.log_period_mean + self.log_period_sigma * normal))
+    }
+}
+
 pub fn generate_teacup_system() -> SerializableStellarSystem {
     let moon_ae_2 = SerializableBody {
         name: "Teacup Ae II".to_string(),
@@ -146,6 +3328,9 @@ pub fn generate_teacup_system() -> SerializableStellarSystem {
             mass: Mass::<EarthMass>::new(0.004),
             radius: Distance::<EarthRadius>::new(0.18),
             active_core: ActiveCore(false),
+            obliquity: Angle::<Radian>::new(0.05),
+            rotation_period: Time::<Hour>::new(96.0), // gebunden an Teacup Ae
+            rings: None,
         }),
         orbit: Some(Orbit {
             semi_major_axis: Distance::<AstronomicalUnit>::new(0.00167),
@@ -163,6 +3348,9 @@ pub fn generate_teacup_system() -> SerializableStellarSystem {
             mass: Mass::<EarthMass>::new(0.8),
             radius: Distance::<EarthRadius>::new(0.96),
             active_core: ActiveCore(true),
+            obliquity: Angle::<Radian>::new(0.38), // ~22 Grad in Radiant
+            rotation_period: Time::<Hour>::new(31.0),
+            rings: None,
         }),
         orbit: Some(Orbit {
             semi_major_axis: Distance::<AstronomicalUnit>::new(0.45),
@@ -190,8 +3378,10 @@ pub fn generate_teacup_system() -> SerializableStellarSystem {
     };
 
     SerializableStellarSystem {
+        schema_version: SerializableStellarSystem::SCHEMA_VERSION,
         name: "Teacup System".to_string(),
         age: Time::<Gigayear>::new(6.0), // 6 Milliarden Jahre
         roots: vec![star_a],
+        debris_disk: None,
     }
 }
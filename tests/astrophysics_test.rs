@@ -0,0 +1,150 @@
+use star_sim::physics::astrophysics::*;
+use star_sim::physics::units::*;
+
+#[test]
+fn test_sun_earth_l1() {
+    let system = LagrangeSystem::new(
+        Mass::<SolarMass>::new(1.0),
+        Mass::<EarthMass>::new(1.0),
+        Distance::<AstronomicalUnit>::new(1.0),
+    );
+    let l1_km = system.l1_distance.convert_to::<Kilometer>().value();
+    assert!((l1_km - 1.49e6).abs() < 0.01e6, "L1 at {l1_km} km");
+    assert!((system.l1_distance.convert_to::<AstronomicalUnit>().value() - 0.01).abs() < 0.001);
+    assert!(system.l2_distance.value() > system.l1_distance.value());
+    assert!(system.has_stable_trojan_points());
+}
+
+#[test]
+fn test_sun_jupiter_differs_from_hill_approximation() {
+    let mass_ratio = 9.54e-4;
+    let system = LagrangeSystem::new(
+        Mass::<SolarMass>::new(1.0),
+        Mass::<SolarMass>::new(mass_ratio),
+        Distance::<Meter>::new(1.0),
+    );
+    // Crude closed form a·(μ/3)^(1/3) lies between the true L1 and L2
+    let hill = (system.mass_parameter / 3.0).cbrt();
+    let (l1, l2) = (system.l1_distance.value(), system.l2_distance.value());
+    assert!(l1 < hill && hill < l2);
+    assert!((l1 - 0.06668).abs() < 1e-4, "L1 = {l1}");
+    assert!((l2 - 0.06978).abs() < 1e-4, "L2 = {l2}");
+    // L3 sits 1 + 5μ/12 from the barycenter, i.e. 1 − 7μ/12 from the primary
+    let l3 = system.l3_distance.value();
+    assert!(
+        (l3 - (1.0 - 7.0 * system.mass_parameter / 12.0)).abs() < 1e-6,
+        "L3 = {l3}"
+    );
+}
+
+#[test]
+fn test_equal_masses_have_unstable_trojan_points() {
+    let system = LagrangeSystem::new(
+        Mass::<SolarMass>::new(1.0),
+        Mass::<SolarMass>::new(1.0),
+        Distance::<AstronomicalUnit>::new(1.0),
+    );
+    assert!(!system.has_stable_trojan_points());
+    assert!((system.l1_distance.convert_to::<AstronomicalUnit>().value() - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn test_io_tidal_heating() {
+    let power = calculate_tidal_heating(
+        Mass::<Kilogram>::new(1.898e27),
+        Distance::<Kilometer>::new(1821.6),
+        Distance::<Kilometer>::new(421_700.0),
+        0.0041,
+        0.015,
+    );
+    // Io radiates roughly 1e14 W of tidal heat
+    assert!(
+        power.value() > 5e13 && power.value() < 3e14,
+        "{}",
+        power.value()
+    );
+}
+
+#[test]
+fn test_earth_moon_roche_limit() {
+    let earth_mass = Mass::<EarthMass>::new(1.0);
+    let earth_radius = Distance::<EarthRadius>::new(1.0);
+    let moon_density = 3344.0;
+
+    let rigid = roche_limit(earth_mass, earth_radius, moon_density);
+    let rigid_km = rigid.convert_to::<Kilometer>().value();
+    assert!((rigid_km - 9500.0).abs() < 100.0, "{rigid_km} km");
+
+    let fluid = fluid_roche_limit(earth_mass, earth_radius, moon_density);
+    let fluid_km = fluid.convert_to::<Kilometer>().value();
+    assert!((fluid_km - 18_400.0).abs() < 300.0, "{fluid_km} km");
+}
+
+#[test]
+fn test_mardling_aarseth_triples() {
+    let inner_mass = Mass::<SolarMass>::new(1.1 + 0.907);
+    let outer_mass = Mass::<SolarMass>::new(0.122);
+    let inner_a = Distance::<AstronomicalUnit>::new(23.4);
+    let coplanar = Angle::<Radian>::new(0.0);
+
+    // Alpha Centauri: Proxima orbits the AB pair at ~8700 AU
+    let proxima_a = Distance::<AstronomicalUnit>::new(8700.0);
+    assert!(is_hierarchical_triple_stable(
+        inner_mass, outer_mass, inner_a, proxima_a, 0.5, coplanar
+    ));
+
+    // The same third star squeezed to 60 AU is disrupted
+    let compact_a = Distance::<AstronomicalUnit>::new(60.0);
+    assert!(!is_hierarchical_triple_stable(
+        inner_mass, outer_mass, inner_a, compact_a, 0.5, coplanar
+    ));
+
+    // Circular, coplanar, light third body: 2.8 · (1 + q)^(2/5)
+    let ratio = mardling_aarseth_critical_ratio(
+        Mass::<SolarMass>::new(1.0),
+        Mass::<SolarMass>::new(0.0),
+        0.0,
+        coplanar,
+    );
+    assert!((ratio - 2.8).abs() < 1e-12);
+    // Eccentric outer orbits need more room, retrograde ones less
+    let eccentric = mardling_aarseth_critical_ratio(inner_mass, outer_mass, 0.5, coplanar);
+    let retrograde =
+        mardling_aarseth_critical_ratio(inner_mass, outer_mass, 0.5, Angle::<Degree>::new(180.0));
+    assert!(eccentric > 2.8 && retrograde < eccentric);
+}
+
+#[test]
+fn test_mardling_aarseth_eccentric_boundary() {
+    // Equal-mass outer star (q = 1) on an e = 0.5 orbit around a 1 AU pair:
+    // R_p / a_in > 2.8 · (2 · 1.5 / √0.5)^(2/5) ≈ 4.99, i.e. a_out ≈ 9.98 AU
+    let inner_mass = Mass::<SolarMass>::new(2.0);
+    let outer_mass = Mass::<SolarMass>::new(2.0);
+    let inner_a = Distance::<AstronomicalUnit>::new(1.0);
+    let coplanar = Angle::<Radian>::new(0.0);
+
+    let ratio = mardling_aarseth_critical_ratio(inner_mass, outer_mass, 0.5, coplanar);
+    assert!((ratio - 4.99).abs() < 0.01, "critical ratio {ratio}");
+
+    let stable = |outer_a: f64| {
+        is_hierarchical_triple_stable(
+            inner_mass,
+            outer_mass,
+            inner_a,
+            Distance::<AstronomicalUnit>::new(outer_a),
+            0.5,
+            coplanar,
+        )
+    };
+    assert!(stable(10.2));
+    assert!(!stable(9.8));
+}
+
+#[test]
+fn test_earth_transit_depth() {
+    let depth = transit_depth(
+        Distance::<SunRadius>::new(1.0),
+        Distance::<EarthRadius>::new(1.0),
+    );
+    assert!((depth - 8.4e-5).abs() < 0.1e-5, "{depth}");
+}
@@ -0,0 +1,34 @@
+use star_sim::physics::mechanics::kinematics::*;
+use std::f64::consts::PI;
+
+#[test]
+fn test_anomalies_are_consistent() {
+    let e = 0.3;
+    let nu = PI / 2.0;
+    let eccentric = true_to_eccentric_anomaly(nu, e);
+    // cos E = (e + cos ν) / (1 + e·cos ν) = 0.3 at ν = 90°
+    assert!((eccentric.cos() - 0.3).abs() < 1e-12);
+
+    let mean = eccentric_to_mean_anomaly(eccentric, e);
+    assert!((mean - (eccentric - e * eccentric.sin())).abs() < 1e-12);
+    assert!((mean_to_eccentric_anomaly(mean, e) - eccentric).abs() < 1e-9);
+    assert!((eccentric_to_true_anomaly(eccentric, e) - nu).abs() < 1e-12);
+}
+
+#[test]
+fn test_unbound_anomalies_round_trip() {
+    for (e, nu) in [(1.5, 1.2), (1.5, -0.8), (3.0, 1.9), (1.0, 2.5), (1.0, -1.0)] {
+        let eccentric = true_to_eccentric_anomaly(nu, e);
+        assert!(
+            (eccentric_to_true_anomaly(eccentric, e) - nu).abs() < 1e-9,
+            "e = {e}"
+        );
+
+        let mean = eccentric_to_mean_anomaly(eccentric, e);
+        let back = mean_to_eccentric_anomaly(mean, e);
+        assert!(
+            (back - eccentric).abs() < 1e-9,
+            "e = {e}: {back} vs {eccentric}"
+        );
+    }
+}
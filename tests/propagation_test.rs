@@ -0,0 +1,87 @@
+//! Accuracy suite for the two-body propagator.
+//!
+//! An Earth-like orbit is propagated with `Orbit::state_vector` (via
+//! `true_anomaly_at_time`). The elements are recovered with
+//! `Orbit::from_state_vector` at every step over 1000 periods.
+//!
+//! Expected tolerances:
+//! - semi-major axis: relative error below 1e-6;
+//! - eccentricity: absolute error below 1e-6.
+//!
+//! The Kepler solver converges to |ΔE| < 1e-10, so the recovered elements
+//! carry errors of about 1e-10. Rounding of the mean anomaly grows linearly
+//! with time (M ≈ 6300 rad after 1000 periods), but stays near 1e-12. Any
+//! drift beyond 1e-6 points to a solver or unit bug, not to round-off.
+
+use star_sim::physics::units::*;
+use star_sim::stellar_objects::Orbit;
+
+const PERIODS: usize = 1000;
+/// Samples per period, deliberately not a divisor of the period so that the
+/// phases sampled wander around the whole orbit.
+const STEPS_PER_PERIOD: usize = 7;
+const SEMI_MAJOR_AXIS_TOLERANCE: f64 = 1e-6;
+const ECCENTRICITY_TOLERANCE: f64 = 1e-6;
+
+fn earth_like() -> Orbit {
+    Orbit {
+        semi_major_axis: Distance::<AstronomicalUnit>::new(1.000_001),
+        eccentricity: 0.0167,
+        inclination: Angle::<Radian>::new(0.000_9),
+        longitude_of_ascending_node: Angle::<Radian>::new(-0.196_5),
+        argument_of_periapsis: Angle::<Radian>::new(1.796_8),
+        mean_anomaly_at_epoch: Angle::<Radian>::new(6.240_1),
+    }
+}
+
+#[test]
+fn test_elements_stay_constant_over_1000_periods() {
+    let mass = Mass::<SolarMass>::new(1.000_003);
+    let orbit = earth_like();
+    let period = orbit.orbital_period(mass).value();
+    let dt = period / STEPS_PER_PERIOD as f64 * 1.003;
+
+    let mut max_a_error: f64 = 0.0;
+    let mut max_e_error: f64 = 0.0;
+    for step in 0..=PERIODS * STEPS_PER_PERIOD {
+        let time = Time::<Second>::new(step as f64 * dt);
+        let (position, velocity) = orbit.state_vector(time, mass);
+        let recovered = Orbit::from_state_vector(position, velocity, mass);
+
+        let a_error =
+            (recovered.semi_major_axis.value() / orbit.semi_major_axis.value() - 1.0).abs();
+        let e_error = (recovered.eccentricity - orbit.eccentricity).abs();
+        assert!(
+            a_error < SEMI_MAJOR_AXIS_TOLERANCE,
+            "a drifts at step {step}"
+        );
+        assert!(e_error < ECCENTRICITY_TOLERANCE, "e drifts at step {step}");
+        max_a_error = max_a_error.max(a_error);
+        max_e_error = max_e_error.max(e_error);
+    }
+
+    // Far inside the documented tolerances; a growing error would show up here first
+    assert!(max_a_error < 1e-9, "max a error {max_a_error:e}");
+    assert!(max_e_error < 1e-9, "max e error {max_e_error:e}");
+}
+
+#[test]
+fn test_position_repeats_after_each_period() {
+    let mass = Mass::<SolarMass>::new(1.0);
+    let orbit = earth_like();
+    let period = orbit.orbital_period(mass).value();
+    let (start, _) = orbit.state_vector(Time::<Second>::new(0.0), mass);
+
+    for n in [1, 10, 100, PERIODS] {
+        let (position, _) = orbit.state_vector(Time::<Second>::new(n as f64 * period), mass);
+        let offset: f64 = (0..3)
+            .map(|k| (position[k].value() - start[k].value()).powi(2))
+            .sum::<f64>()
+            .sqrt();
+        // Below a metre per AU and period, i.e. a relative error of about 1e-11 per orbit
+        assert!(
+            offset < 1.0 * n as f64 + 10.0,
+            "offset {offset} m after {n} periods"
+        );
+    }
+}
@@ -26,3 +26,189 @@ unit_serialization_test!(pressure_pascal, Pressure<Pascal>, 101325.0);
 unit_serialization_test!(energy_joule, Energy<Joule>, 500.0);
 unit_serialization_test!(power_watt, Power<Watt>, 1200.0);
 unit_serialization_test!(force_newton, Force<Newton>, 10.0);
+
+#[test]
+fn non_finite_system_round_trip() {
+    use star_sim::stellar_objects::*;
+
+    let mut system = generate_teacup_system();
+    assert!(system.validate().is_ok());
+
+    if let BodyKind::Star(star) = &mut system.roots[0].kind {
+        star.luminosity = Power::<SolarLuminosity>::new(f64::NAN);
+        star.temperature = Temperature::<Kelvin>::new(f64::INFINITY);
+    }
+
+    let errors = system.validate().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(errors.iter().any(|e| e.contains("luminosity")));
+
+    let ron_string = ron::to_string(&system).unwrap();
+    let deserialized: SerializableStellarSystem = ron::from_str(&ron_string).unwrap();
+    match &deserialized.roots[0].kind {
+        BodyKind::Star(star) => {
+            assert!(star.luminosity.value().is_nan());
+            assert!(star.temperature.value().is_nan());
+            assert!((star.mass.value() - 0.7).abs() < f64::EPSILON);
+        }
+        _ => panic!("expected a star"),
+    }
+    assert_eq!(deserialized.validate().unwrap_err().len(), 2);
+}
+
+#[cfg(feature = "bincode")]
+#[test]
+fn bincode_round_trip_is_compact() {
+    use star_sim::stellar_objects::*;
+
+    let mut system = generate_teacup_system();
+    system.roots[0].satellites.push(SerializableBody {
+        name: "Teacup B".to_string(),
+        kind: BodyKind::Star(StarData {
+            mass: Mass::<SolarMass>::new(0.3),
+            radius: Distance::<SunRadius>::new(0.3),
+            temperature: Temperature::<Kelvin>::new(3400.0),
+            luminosity: Power::<SolarLuminosity>::new(0.01),
+            spectral_type: SpectralType::M(3),
+            luminosity_class: LuminosityClass::V,
+        }),
+        orbit: Some(Orbit {
+            semi_major_axis: Distance::<AstronomicalUnit>::new(40.0),
+            eccentricity: 0.3,
+            ..Default::default()
+        }),
+        satellites: vec![],
+    });
+    assert_eq!(system.stars().len(), 2);
+
+    let bytes = system.to_bincode().unwrap();
+    assert!(bytes.len() * 2 < system.serialized_size().unwrap());
+
+    let deserialized = SerializableStellarSystem::from_bincode(&bytes).unwrap();
+    // Identical reconstruction: every enum and nested quantity survives unchanged
+    assert_eq!(
+        ron::to_string(&deserialized).unwrap(),
+        ron::to_string(&system).unwrap()
+    );
+}
+
+#[test]
+fn json_round_trip() {
+    use star_sim::stellar_objects::*;
+
+    let system = generate_teacup_system();
+    for pretty in [false, true] {
+        let json = system.to_json_string(pretty).unwrap();
+        assert_eq!(json.contains('\n'), pretty);
+        let deserialized = SerializableStellarSystem::from_json_string(&json).unwrap();
+
+        assert_eq!(deserialized.name, system.name);
+        assert_eq!(deserialized.age, system.age);
+        assert_eq!(deserialized.roots.len(), system.roots.len());
+        assert!(matches!(deserialized.roots[0].kind, BodyKind::Star(_)));
+        assert_eq!(
+            deserialized.to_json_string(false).unwrap(),
+            system.to_json_string(false).unwrap()
+        );
+    }
+
+    let ron = system.to_ron_string(true).unwrap();
+    let from_ron = SerializableStellarSystem::from_ron_string(&ron).unwrap();
+    assert_eq!(
+        from_ron.to_json_string(false).unwrap(),
+        system.to_json_string(false).unwrap()
+    );
+
+    assert!(matches!(
+        SerializableStellarSystem::from_json_string("{\"name\": 3}"),
+        Err(SystemFormatError::Json(_))
+    ));
+    assert!(SerializableStellarSystem::from_ron_string("(name: )").is_err());
+
+    // Parse errors keep the position of the offending token
+    match SerializableStellarSystem::from_ron_string("(\n    name: \"X\",\n    age: ,\n)") {
+        Err(SystemFormatError::RonParse(error)) => {
+            assert_eq!(error.position.line, 3);
+            assert!(error.to_string().starts_with("3:"), "{error}");
+        }
+        other => panic!("expected a RON parse error, got {other:?}"),
+    }
+}
+
+#[cfg(feature = "bincode")]
+#[test]
+fn bytes_round_trip() {
+    use star_sim::stellar_objects::*;
+
+    let mut system = generate_teacup_system();
+    system.place_debris_disk(0.2);
+    let bytes = system.to_bytes();
+    let ron = system.to_ron_string(false).unwrap();
+    assert!(
+        bytes.len() * 2 < ron.len(),
+        "{} bytes vs {} RON",
+        bytes.len(),
+        ron.len()
+    );
+
+    let restored = SerializableStellarSystem::from_bytes(&bytes).unwrap();
+    assert_eq!(restored.to_ron_string(false).unwrap(), ron);
+    assert!(SerializableStellarSystem::from_bytes(&bytes[..bytes.len() / 2]).is_err());
+}
+
+#[test]
+fn older_schema_still_loads() {
+    use star_sim::stellar_objects::*;
+
+    // Written before schema_version, debris disks and planet spin existed
+    let old = r#"(
+        name: "Legacy",
+        age: (value: 4.6, _unit: (), _dims: ()),
+        roots: [(
+            name: "Star",
+            kind: Star((
+                mass: (value: 1.0, _unit: (), _dims: ()),
+                radius: (value: 1.0, _unit: (), _dims: ()),
+                temperature: (value: 5778.0, _unit: (), _dims: ()),
+                luminosity: (value: 1.0, _unit: (), _dims: ()),
+                spectral_type: G(2),
+                luminosity_class: V,
+            )),
+            orbit: None,
+            satellites: [(
+                name: "Planet",
+                kind: Planet((
+                    body_type: Rocky,
+                    mass: (value: 1.0, _unit: (), _dims: ()),
+                    radius: (value: 1.0, _unit: (), _dims: ()),
+                    active_core: (true),
+                )),
+                orbit: None,
+                satellites: [],
+            )],
+        )],
+    )"#;
+    let system = SerializableStellarSystem::from_ron_string(old).unwrap();
+    assert_eq!(system.schema_version, 0);
+    assert!(system.debris_disk.is_none());
+    assert!(system.validate().is_ok());
+    match &system.roots[0].satellites[0].kind {
+        BodyKind::Planet(planet) => {
+            assert!(planet.rings.is_none());
+            assert_eq!(planet.rotation_period.value(), 24.0);
+        }
+        _ => panic!("expected a planet"),
+    }
+
+    let current = generate_teacup_system();
+    assert_eq!(
+        current.schema_version,
+        SerializableStellarSystem::SCHEMA_VERSION
+    );
+    let reloaded =
+        SerializableStellarSystem::from_ron_string(&current.to_ron_string(false).unwrap()).unwrap();
+    assert_eq!(
+        reloaded.schema_version,
+        SerializableStellarSystem::SCHEMA_VERSION
+    );
+}
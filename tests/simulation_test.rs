@@ -0,0 +1,128 @@
+use bevy::prelude::*;
+use star_sim::physics::units::{
+    Angle, AstronomicalUnit, Distance, EarthMass, EarthRadius, Gigayear, Hour, Kelvin, Mass, Power,
+    Radian, SolarLuminosity, SolarMass, SunRadius, Temperature, Time, Year,
+};
+use star_sim::simulation::{
+    ActiveStarSystem, OrbitEntity, PlanetEntity, SimulationTime, StarEntity, StarSystemPlugin,
+};
+use star_sim::stellar_objects::*;
+use std::time::Duration;
+
+fn star(mass: f64, spectral_type: SpectralType) -> StarData {
+    StarData {
+        mass: Mass::<SolarMass>::new(mass),
+        radius: Distance::<SunRadius>::new(mass.powf(0.8)),
+        temperature: Temperature::<Kelvin>::new(5778.0),
+        luminosity: Power::<SolarLuminosity>::new(mass.powi(4)),
+        spectral_type,
+        luminosity_class: LuminosityClass::V,
+    }
+}
+
+fn binary() -> SerializableStellarSystem {
+    SerializableStellarSystem {
+        schema_version: SerializableStellarSystem::SCHEMA_VERSION,
+        name: "Binary".to_string(),
+        age: Time::<Gigayear>::new(4.6),
+        roots: vec![SerializableBody {
+            name: "A".to_string(),
+            kind: BodyKind::Star(star(1.0, SpectralType::G(2))),
+            orbit: None,
+            satellites: vec![SerializableBody {
+                name: "B".to_string(),
+                kind: BodyKind::Star(star(0.5, SpectralType::M(0))),
+                orbit: Some(Orbit {
+                    semi_major_axis: Distance::<AstronomicalUnit>::new(20.0),
+                    ..Default::default()
+                }),
+                satellites: vec![],
+            }],
+        }],
+        debris_disk: None,
+    }
+}
+
+#[test]
+fn test_spawn_binary_system() {
+    let mut app = App::new();
+    app.add_plugins(StarSystemPlugin)
+        .insert_resource(ActiveStarSystem(binary()));
+    app.update();
+
+    let world = app.world_mut();
+    let stars: Vec<(String, Vec3)> = world
+        .query::<(&Name, &StarEntity, &Transform)>()
+        .iter(world)
+        .map(|(name, _, transform)| (name.to_string(), transform.translation))
+        .collect();
+    assert_eq!(stars.len(), 2);
+    assert_ne!(stars[0].1, stars[1].1);
+
+    // The companion sits one semi-major axis away from the primary
+    let (_, companion) = stars.iter().find(|(name, _)| name == "B").unwrap();
+    assert!((companion.length() - 20.0).abs() < 1e-3);
+
+    let orbits = world.query::<&OrbitEntity>().iter(world).count();
+    assert_eq!(orbits, 1);
+}
+
+#[test]
+fn test_advance_orbits_follows_orbital_period() {
+    let earth = PlanetData {
+        body_type: BodyType::Rocky,
+        mass: Mass::<EarthMass>::new(1.0),
+        radius: Distance::<EarthRadius>::new(1.0),
+        active_core: ActiveCore(true),
+        obliquity: Angle::<Radian>::new(0.409),
+        rotation_period: Time::<Hour>::new(24.0),
+        rings: None,
+    };
+    let system = SerializableStellarSystem {
+        schema_version: SerializableStellarSystem::SCHEMA_VERSION,
+        name: "Sol".to_string(),
+        age: Time::<Gigayear>::new(4.6),
+        roots: vec![SerializableBody {
+            name: "Sonne".to_string(),
+            kind: BodyKind::Star(star(1.0, SpectralType::G(2))),
+            orbit: None,
+            satellites: vec![SerializableBody {
+                name: "Erde".to_string(),
+                kind: BodyKind::Planet(earth),
+                orbit: Some(Orbit::default()),
+                satellites: vec![],
+            }],
+        }],
+        debris_disk: None,
+    };
+
+    let mut app = App::new();
+    app.add_plugins(StarSystemPlugin)
+        .insert_resource(ActiveStarSystem(system))
+        .insert_resource(SimulationTime::with_scale(2.0));
+
+    // 10 ticks of 12.5 ms at 2 years per second make a quarter year
+    for _ in 0..10 {
+        app.world_mut()
+            .resource_mut::<bevy::time::Time>()
+            .advance_by(Duration::from_micros(12_500));
+        app.update();
+    }
+
+    let elapsed = app.world().resource::<SimulationTime>().elapsed;
+    assert!((elapsed.value() - 0.25).abs() < 1e-9);
+
+    let world = app.world_mut();
+    let (orbit, transform) = world
+        .query_filtered::<(&OrbitEntity, &Transform), With<PlanetEntity>>()
+        .single(world);
+    let period = orbit
+        .orbit
+        .orbital_period(orbit.total_mass)
+        .convert_to::<Year>()
+        .value();
+    let expected = std::f64::consts::TAU * elapsed.value() / period;
+    let angle = f64::from(transform.translation.y).atan2(f64::from(transform.translation.x));
+    assert!((angle - expected).abs() < 1e-3);
+    assert!((transform.translation.length() - 1.0).abs() < 1e-4);
+}
@@ -0,0 +1,1948 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use star_sim::physics::astrophysics::{approximate_main_sequence_lifetime, transit_depth};
+use star_sim::physics::constants::G;
+use star_sim::physics::units::*;
+use star_sim::stellar_objects::*;
+
+fn sun() -> StarData {
+    StarData {
+        mass: Mass::<SolarMass>::new(1.0),
+        radius: Distance::<SunRadius>::new(1.0),
+        temperature: Temperature::<Kelvin>::new(5778.0),
+        luminosity: Power::<SolarLuminosity>::new(1.0),
+        spectral_type: SpectralType::G(2),
+        luminosity_class: LuminosityClass::V,
+    }
+}
+
+fn earth() -> PlanetData {
+    PlanetData {
+        body_type: BodyType::Rocky,
+        mass: Mass::<EarthMass>::new(1.0),
+        radius: Distance::<EarthRadius>::new(1.0),
+        active_core: ActiveCore(true),
+        obliquity: Angle::<Radian>::new(0.409),
+        rotation_period: Time::<Hour>::new(24.0),
+        rings: None,
+    }
+}
+
+#[test]
+fn test_sun_density_and_gravity() {
+    let sun = sun();
+    assert!((sun.mean_density() - 1408.0).abs() < 10.0);
+    assert!((sun.surface_gravity().value() - 274.0).abs() < 2.0);
+}
+
+#[test]
+fn test_earth_surface_gravity() {
+    let earth = earth();
+    assert!((earth.surface_gravity().value() - 9.8).abs() < 0.05);
+    assert!((earth.mean_density() - 5514.0).abs() < 20.0);
+}
+
+#[test]
+fn test_thermal_eccentricities_exceed_uniform() {
+    let mut rng = ChaCha8Rng::seed_from_u64(42);
+    let period = Time::<Day>::new(10_000.0);
+    let high_e_fraction = |dist: EccentricityDistribution, rng: &mut ChaCha8Rng| {
+        (0..10_000)
+            .filter(|_| dist.sample(period, rng) > 0.6)
+            .count() as f64
+            / 10_000.0
+    };
+
+    let thermal = high_e_fraction(EccentricityDistribution::Thermal, &mut rng);
+    let uniform = high_e_fraction(EccentricityDistribution::Uniform, &mut rng);
+    assert!(
+        thermal > uniform + 0.2,
+        "thermal {thermal}, uniform {uniform}"
+    );
+}
+
+#[test]
+fn test_close_binaries_are_circularized() {
+    let mut rng = ChaCha8Rng::seed_from_u64(7);
+    let dist = EccentricityDistribution::CircularizedBelow {
+        period: Time::<Day>::new(12.0),
+    };
+
+    for _ in 0..1000 {
+        assert!(dist.sample(Time::<Day>::new(3.0), &mut rng) < 1e-3);
+    }
+    let wide: f64 = (0..1000)
+        .map(|_| dist.sample(Time::<Year>::new(100.0), &mut rng))
+        .sum::<f64>()
+        / 1000.0;
+    assert!(wide > 0.5);
+}
+
+#[test]
+fn test_close_binary_circularizes() {
+    let primary = sun();
+    let secondary = sun();
+    let elapsed = Time::<Gigayear>::new(3.0);
+
+    let close = Orbit {
+        semi_major_axis: Distance::<AstronomicalUnit>::new(0.05),
+        eccentricity: 0.5,
+        ..Default::default()
+    };
+    let wide = Orbit {
+        semi_major_axis: Distance::<AstronomicalUnit>::new(1.0),
+        eccentricity: 0.5,
+        ..Default::default()
+    };
+
+    let close_evolved = close.tidally_evolved(&primary, &secondary, elapsed);
+    let wide_evolved = wide.tidally_evolved(&primary, &secondary, elapsed);
+    assert!(close_evolved.eccentricity < 0.01);
+    assert!((wide_evolved.eccentricity - 0.5).abs() < 1e-3);
+
+    let tau_close = close
+        .circularization_timescale(&primary, &secondary)
+        .value();
+    let tau_wide = wide.circularization_timescale(&primary, &secondary).value();
+    // τ ∝ a^6.5, so a 20x wider orbit is more than 10⁸ times slower
+    assert!(tau_wide / tau_close > 1e8);
+}
+
+#[test]
+fn test_resonance_chain_spacing() {
+    let innermost = Distance::<AstronomicalUnit>::new(0.011);
+    let ratios = vec![(8, 5), (5, 3), (3, 2), (3, 2), (4, 3), (3, 2)];
+    let axes = PlanetSpacing::ResonanceChain(ratios.clone()).semi_major_axes(innermost, 10);
+
+    assert_eq!(axes.len(), ratios.len() + 1);
+    assert!((axes[0].value() - 0.011).abs() < f64::EPSILON);
+
+    let star_mass = Mass::<SolarMass>::new(0.09);
+    for (pair, &(p, q)) in axes.windows(2).zip(&ratios) {
+        let inner = Orbit {
+            semi_major_axis: pair[0],
+            ..Default::default()
+        };
+        let outer = Orbit {
+            semi_major_axis: pair[1],
+            ..Default::default()
+        };
+        let period_ratio =
+            outer.orbital_period(star_mass).value() / inner.orbital_period(star_mass).value();
+        assert!((period_ratio - p as f64 / q as f64).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_geometric_spacing() {
+    let axes = PlanetSpacing::Geometric { ratio: 2.0 }
+        .semi_major_axes(Distance::<AstronomicalUnit>::new(0.4), 4);
+    let values: Vec<f64> = axes.iter().map(|a| a.value()).collect();
+    assert_eq!(values, vec![0.4, 0.8, 1.6, 3.2]);
+}
+
+#[test]
+fn test_activity_declines_with_age() {
+    let sun = sun();
+    let young = sun.activity(Time::<Gigayear>::new(0.05));
+    assert!(young.rotation_period.value() < 3.0);
+    assert!(young.xray_luminosity_fraction > 1e-4);
+    assert!(young.flare_frequency > 1.0);
+
+    let today = sun.activity(Time::<Gigayear>::new(4.6));
+    assert!((today.rotation_period.value() - 25.4).abs() < 0.5);
+
+    let ages = [0.05, 0.3, 1.0, 4.6, 9.0];
+    let activities: Vec<StellarActivity> = ages
+        .iter()
+        .map(|&age| sun.activity(Time::<Gigayear>::new(age)))
+        .collect();
+    for pair in activities.windows(2) {
+        assert!(pair[1].rotation_period.value() > pair[0].rotation_period.value());
+        assert!(pair[1].xray_luminosity_fraction <= pair[0].xray_luminosity_fraction);
+        assert!(pair[1].flare_frequency <= pair[0].flare_frequency);
+    }
+    assert!(activities[4].flare_frequency < activities[0].flare_frequency / 10.0);
+}
+
+#[test]
+fn test_flare_generation() {
+    let mut rng = ChaCha8Rng::seed_from_u64(3);
+    let age = Time::<Gigayear>::new(4.6);
+    let duration = Time::<Year>::new(1000.0);
+
+    let dwarf = star(0.2, SpectralType::M(5), LuminosityClass::V);
+    let dwarf_flares = dwarf.generate_flares(age, duration, &mut rng);
+    let sun_flares = sun().generate_flares(age, duration, &mut rng);
+    assert!(
+        dwarf_flares.len() > 3 * sun_flares.len(),
+        "{} vs {}",
+        dwarf_flares.len(),
+        sun_flares.len()
+    );
+
+    let days = duration.convert_to::<Day>().value();
+    for pair in dwarf_flares.windows(2) {
+        assert!(pair[1].time_offset.value() > pair[0].time_offset.value());
+    }
+    assert!(dwarf_flares.iter().all(|flare| {
+        flare.time_offset.value() <= days
+            && flare.energy.value() >= 1e32
+            && flare.duration.value() >= 10.0
+    }));
+
+    // Most flares sit near the lower energy bound of the power law
+    let strong = dwarf_flares
+        .iter()
+        .filter(|flare| flare.energy.value() > 1e33)
+        .count();
+    assert!(strong < dwarf_flares.len() / 5);
+}
+
+#[test]
+fn test_seasonal_insolation_depends_on_obliquity() {
+    let sun = sun();
+    let orbit = Orbit::default();
+    let latitude = Angle::<Degree>::new(45.0).convert_to::<Radian>();
+
+    let mut planet = earth();
+    planet.obliquity = Angle::<Degree>::new(5.0).convert_to::<Radian>();
+    let (low_min, low_max) = planet.seasonal_insolation_range(&sun, &orbit, latitude);
+    planet.obliquity = Angle::<Degree>::new(60.0).convert_to::<Radian>();
+    let (high_min, high_max) = planet.seasonal_insolation_range(&sun, &orbit, latitude);
+    assert!(high_max - high_min > 3.0 * (low_max - low_min));
+
+    planet.obliquity = Angle::<Radian>::new(0.0);
+    let equator = Angle::<Radian>::new(0.0);
+    let (min, max) = planet.seasonal_insolation_range(&sun, &orbit, equator);
+    assert!((max - min).abs() < 1e-9);
+    // 1361 W/m² / π at the equator without seasons
+    assert!((max - 433.0).abs() < 2.0);
+}
+
+#[test]
+fn test_seasonal_insolation_ignores_argument_of_periapsis() {
+    let sun = sun();
+    let planet = earth();
+    let latitude = Angle::<Degree>::new(45.0).convert_to::<Radian>();
+    let eccentric = |argument_of_periapsis: f64| Orbit {
+        eccentricity: 0.3,
+        argument_of_periapsis: Angle::<Radian>::new(argument_of_periapsis),
+        ..Default::default()
+    };
+
+    // The vernal equinox points to periapsis whatever the orbit's orientation
+    let (min, max) = planet.seasonal_insolation_range(&sun, &eccentric(0.0), latitude);
+    let (turned_min, turned_max) =
+        planet.seasonal_insolation_range(&sun, &eccentric(2.0), latitude);
+    assert!((turned_min - min).abs() < 1e-9 && (turned_max - max).abs() < 1e-9);
+
+    // The eccentricity still widens the seasonal range
+    let (circular_min, circular_max) =
+        planet.seasonal_insolation_range(&sun, &Orbit::default(), latitude);
+    assert!(max - min > circular_max - circular_min);
+}
+
+fn star(mass: f64, spectral_type: SpectralType, luminosity_class: LuminosityClass) -> StarData {
+    StarData {
+        mass: Mass::<SolarMass>::new(mass),
+        spectral_type,
+        luminosity_class,
+        ..sun()
+    }
+}
+
+fn binary(primary: StarData, secondary: StarData) -> SerializableStellarSystem {
+    SerializableStellarSystem {
+        schema_version: SerializableStellarSystem::SCHEMA_VERSION,
+        name: "Binary".to_string(),
+        age: Time::<Gigayear>::new(4.6),
+        roots: vec![SerializableBody {
+            name: "A".to_string(),
+            kind: BodyKind::Star(primary),
+            orbit: None,
+            satellites: vec![SerializableBody {
+                name: "B".to_string(),
+                kind: BodyKind::Star(secondary),
+                orbit: Some(Orbit {
+                    semi_major_axis: Distance::<AstronomicalUnit>::new(20.0),
+                    ..Default::default()
+                }),
+                satellites: vec![],
+            }],
+        }],
+        debris_disk: None,
+    }
+}
+
+#[test]
+fn test_classify_binary() {
+    let sun_and_red_dwarf = binary(
+        star(0.2, SpectralType::M(4), LuminosityClass::V),
+        star(1.0, SpectralType::G(2), LuminosityClass::V),
+    );
+    assert_eq!(
+        sun_and_red_dwarf.classify_binary().as_deref(),
+        Some("G2V + M4V binary")
+    );
+
+    let giant_pair = binary(
+        star(1.1, SpectralType::K(1), LuminosityClass::III),
+        star(1.0, SpectralType::G(2), LuminosityClass::V),
+    );
+    assert_eq!(
+        giant_pair.classify_binary().as_deref(),
+        Some("K1III giant + G2V main-sequence binary")
+    );
+
+    let white_dwarf_pair = binary(
+        star(0.6, SpectralType::D, LuminosityClass::VII),
+        star(0.3, SpectralType::M(3), LuminosityClass::V),
+    );
+    assert_eq!(
+        white_dwarf_pair.classify_binary().as_deref(),
+        Some("white-dwarf + M-dwarf pair")
+    );
+
+    assert_eq!(generate_teacup_system().classify_binary(), None);
+}
+
+#[test]
+fn test_magnetic_shielding() {
+    let age = Time::<Gigayear>::new(4.6);
+    let earth = earth();
+    let moment = earth.magnetic_moment_estimate(age);
+    assert!((moment / 8.0e22 - 1.0).abs() < 0.05);
+
+    let standoff = earth.magnetopause_standoff(&sun(), Distance::<AstronomicalUnit>::new(1.0), age);
+    let standoff_radii = standoff.convert_to::<EarthRadius>().value();
+    assert!(standoff_radii > 8.0 && standoff_radii < 13.0);
+    assert!(earth.is_magnetically_shielded(&sun(), Distance::<AstronomicalUnit>::new(1.0), age));
+
+    let m_dwarf = StarData {
+        mass: Mass::<SolarMass>::new(0.2),
+        radius: Distance::<SunRadius>::new(0.22),
+        temperature: Temperature::<Kelvin>::new(3200.0),
+        luminosity: Power::<SolarLuminosity>::new(0.005),
+        spectral_type: SpectralType::M(4),
+        luminosity_class: LuminosityClass::V,
+    };
+    // In the habitable zone the planet locks to its ~15 day orbit; the weaker
+    // dynamo and the strong young wind leave barely a quarter of Earth's magnetosphere
+    let habitable_zone = Distance::<AstronomicalUnit>::new(0.07);
+    let young = Time::<Gigayear>::new(0.1);
+    let locked_standoff = earth
+        .magnetopause_standoff(&m_dwarf, habitable_zone, young)
+        .convert_to::<EarthRadius>()
+        .value();
+    assert!(locked_standoff < 0.3 * standoff_radii);
+    assert!(locked_standoff < 2.5);
+}
+
+#[test]
+fn test_metal_rich_systems_have_heavier_debris_disks() {
+    let mut solar = generate_teacup_system();
+    solar.place_debris_disk(0.0);
+    let mut metal_rich = generate_teacup_system();
+    metal_rich.place_debris_disk(0.3);
+
+    let solar_disk = solar.debris_disk.unwrap();
+    let rich_disk = metal_rich.debris_disk.unwrap();
+    // Placed beyond Teacup Ae at 0.45 AU, not its moon
+    assert!(solar_disk.inner_edge.value() > 0.45);
+    assert!(solar_disk.inner_edge.value() < 1.0);
+    assert!(rich_disk.mass.value() > 1.9 * solar_disk.mass.value());
+    assert!(rich_disk.impact_risk_factor() > solar_disk.impact_risk_factor());
+}
+
+#[test]
+fn test_inclination_models() {
+    let mut rng = ChaCha8Rng::seed_from_u64(11);
+    let inner = Orbit::default();
+    let mutual_degrees = |model: InclinationModel, rng: &mut ChaCha8Rng| -> Vec<f64> {
+        (0..500)
+            .map(|_| {
+                let a = model.orient(inner, rng);
+                let b = model.orient(inner, rng);
+                a.mutual_inclination(&b).convert_to::<Degree>().value()
+            })
+            .collect()
+    };
+
+    let coplanar = mutual_degrees(InclinationModel::Coplanar, &mut rng);
+    assert!(coplanar.iter().all(|&i| i.abs() < 1e-9));
+
+    let isotropic = mutual_degrees(InclinationModel::IsotropicRandom, &mut rng);
+    // Kozai-Lidov window: 39.2° < i < 140.8°
+    let in_kozai_window = isotropic.iter().filter(|&&i| i > 39.2 && i < 140.8).count();
+    assert!(
+        in_kozai_window > 300,
+        "{in_kozai_window} of 500 in the Kozai window"
+    );
+    assert!(isotropic.iter().any(|&i| i < 20.0));
+    assert!(isotropic.iter().any(|&i| i > 160.0));
+
+    let flat = mutual_degrees(InclinationModel::Rayleigh { sigma_deg: 1.0 }, &mut rng);
+    assert!(flat.iter().all(|&i| i < 15.0));
+}
+
+#[test]
+fn test_approximate_lifetime_matches_star_data() {
+    for mass in [0.1, 0.3, 0.8, 1.0, 2.0, 5.0, 10.0] {
+        // Main-sequence mass-luminosity relation L ∝ M^3.5
+        let star = StarData {
+            mass: Mass::<SolarMass>::new(mass),
+            luminosity: Power::<SolarLuminosity>::new(f64::powf(mass, 3.5)),
+            ..sun()
+        };
+        let full = star.main_sequence_lifetime().value();
+        let quick = approximate_main_sequence_lifetime(mass).value();
+        assert!(
+            (quick / full - 1.0).abs() < 0.1,
+            "{mass} M☉: {quick} vs {full}"
+        );
+    }
+    assert!((approximate_main_sequence_lifetime(1.0).value() - 10.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_wind_mass_loss() {
+    let o_star = StarData {
+        radius: Distance::<SunRadius>::new(12.0),
+        temperature: Temperature::<Kelvin>::new(40_000.0),
+        luminosity: Power::<SolarLuminosity>::new(4.0e5),
+        ..star(40.0, SpectralType::O(5), LuminosityClass::V)
+    };
+    let rate = o_star.mass_loss_rate();
+    assert!(rate.value() > 1e-6, "{}", rate);
+
+    // Without wind the lifetime would be the plain 10 Gyr · M/L
+    let no_wind = 10.0 * 40.0 / 4.0e5;
+    assert!(o_star.main_sequence_lifetime().value() < no_wind);
+
+    // The Sun's wind is negligible and leaves its lifetime untouched
+    assert!(sun().mass_loss_rate().value() < 1e-12);
+    assert!((sun().main_sequence_lifetime().value() - 10.0).abs() < 1e-9);
+}
+
+fn moon(name: &str, distance_km: f64, inclination_deg: f64) -> SerializableBody {
+    SerializableBody {
+        name: name.to_string(),
+        kind: BodyKind::Barycenter,
+        orbit: Some(Orbit {
+            semi_major_axis: Distance::<Kilometer>::new(distance_km)
+                .convert_to::<AstronomicalUnit>(),
+            eccentricity: 0.0,
+            inclination: Angle::<Degree>::new(inclination_deg).convert_to::<Radian>(),
+            ..Default::default()
+        }),
+        satellites: vec![],
+    }
+}
+
+#[test]
+fn test_saturn_like_rings() {
+    let mut saturn = PlanetData {
+        body_type: BodyType::GasGiant,
+        mass: Mass::<EarthMass>::new(95.2),
+        radius: Distance::<EarthRadius>::new(9.14),
+        obliquity: Angle::<Degree>::new(26.7).convert_to::<Radian>(),
+        ..earth()
+    };
+    let rings = RingSystem::within_roche_limit(&saturn);
+    // Saturn's main rings end near 137 000 km
+    assert!((rings.outer_radius.value() - 137_000.0).abs() < 15_000.0);
+    saturn.rings = Some(rings);
+
+    let body = SerializableBody {
+        name: "Saturn".to_string(),
+        kind: BodyKind::Planet(saturn),
+        orbit: None,
+        satellites: vec![
+            moon("Mimas", 185_540.0, 1.57),
+            moon("Equatorial", 300_000.0, 0.0),
+        ],
+    };
+    assert!(body.has_stable_rings());
+    assert_eq!(body.ring_shadowed_moons(), vec!["Mimas"]);
+
+    let inside_rings = SerializableBody {
+        satellites: vec![moon("Grazer", 100_000.0, 0.0)],
+        ..body
+    };
+    assert!(!inside_rings.has_stable_rings());
+
+    let small = SerializableBody {
+        name: "Rock".to_string(),
+        kind: BodyKind::Planet(earth()),
+        orbit: None,
+        satellites: vec![],
+    };
+    assert!(!small.has_stable_rings());
+    assert!(small.ring_shadowed_moons().is_empty());
+}
+
+#[test]
+fn test_angular_momentum_is_conserved() {
+    let solar = Mass::<SolarMass>::new(1.0);
+    for e in [0.0, 0.1, 0.5, 0.9, 0.99] {
+        let orbit = Orbit {
+            semi_major_axis: Distance::<AstronomicalUnit>::new(5.2),
+            eccentricity: e,
+            ..Default::default()
+        };
+        assert!(orbit.verify_angular_momentum(Mass::<SolarMass>::new(1.0)));
+        assert!(orbit.verify_angular_momentum(solar.convert_to::<Kilogram>()));
+        assert!(orbit.velocity_at_periapsis(solar) >= orbit.velocity_at_apoapsis(solar));
+    }
+
+    // Earth's mean orbital speed is about 29.8 km/s
+    let earth_orbit = Orbit::default();
+    let v = earth_orbit.velocity_at_periapsis(solar).value();
+    assert!((v - 29_780.0).abs() < 100.0);
+}
+
+#[test]
+fn test_all_bodies_of_binary_with_planets() {
+    let mut system = binary(sun(), star(0.5, SpectralType::M(0), LuminosityClass::V));
+    for (name, au) in [("A b", 0.5), ("A c", 1.2)] {
+        system.roots[0].satellites.push(SerializableBody {
+            name: name.to_string(),
+            kind: BodyKind::Planet(earth()),
+            orbit: Some(Orbit {
+                semi_major_axis: Distance::<AstronomicalUnit>::new(au),
+                ..Default::default()
+            }),
+            satellites: vec![],
+        });
+    }
+
+    let bodies = system.all_bodies();
+    assert_eq!(bodies.len(), 4);
+
+    let total: Mass<Kilogram> = bodies.iter().map(|body| body.mass()).sum();
+    let expected = Mass::<SolarMass>::new(1.5).convert_to::<Kilogram>().value()
+        + 2.0 * Mass::<EarthMass>::new(1.0).convert_to::<Kilogram>().value();
+    assert!((total.value() / expected - 1.0).abs() < 1e-12);
+
+    let ids: std::collections::HashSet<u64> = bodies.iter().map(|body| body.body_id()).collect();
+    assert_eq!(ids.len(), bodies.len());
+
+    // Positions follow the chain of orbits down from the root
+    let year = Orbit::default().orbital_period(Mass::<SolarMass>::new(1.0));
+    for body in &bodies {
+        let r = body.position_at(Time::<Second>::new(0.0));
+        let distance = r.map(|x| x.convert_to::<AstronomicalUnit>().value());
+        let distance = distance.iter().map(|x| x * x).sum::<f64>().sqrt();
+        let expected = match body.body.name.as_str() {
+            "A" => 0.0,
+            "B" => 20.0,
+            "A b" => 0.5,
+            _ => 1.2,
+        };
+        assert!((distance - expected).abs() < 1e-9, "{}", body.body.name);
+    }
+    let c = bodies.iter().find(|body| body.body.name == "A c").unwrap();
+    let [x, y, _] = c.position_at(year / 2.0);
+    assert!(y.value().atan2(x.value()).abs() > 2.0);
+
+    // Moons named alike around B and "A c" still get distinct IDs
+    for planet in system.roots[0].satellites.iter_mut().step_by(2) {
+        planet.satellites.push(SerializableBody {
+            name: "b".to_string(),
+            kind: BodyKind::Planet(earth()),
+            orbit: Some(Orbit {
+                semi_major_axis: Distance::<AstronomicalUnit>::new(0.01),
+                ..Default::default()
+            }),
+            satellites: vec![],
+        });
+    }
+    let bodies = system.all_bodies();
+    let ids: std::collections::HashSet<u64> = bodies.iter().map(|body| body.body_id()).collect();
+    assert_eq!(bodies.len(), 6);
+    assert_eq!(ids.len(), bodies.len());
+    let moon = bodies.iter().find(|body| body.body.name == "b").unwrap();
+    let r = moon.position_at(Time::<Second>::new(0.0));
+    assert!((r[0].convert_to::<AstronomicalUnit>().value() - 20.01).abs() < 1e-9);
+}
+
+#[test]
+fn test_true_anomaly_returns_after_one_period() {
+    let mass = Mass::<SolarMass>::new(1.0);
+    let orbit = Orbit {
+        semi_major_axis: Distance::<AstronomicalUnit>::new(2.0),
+        eccentricity: 0.7,
+        mean_anomaly_at_epoch: Angle::<Radian>::new(1.0),
+        ..Default::default()
+    };
+    let epoch = orbit
+        .true_anomaly_at_time(Time::<Second>::new(0.0), mass)
+        .value();
+    let period = orbit.orbital_period(mass);
+    let after = orbit.true_anomaly_at_time(period, mass).value();
+    assert!((after - epoch).abs() < 1e-8, "{epoch} vs {after}");
+
+    // The first-order approximation E ≈ M + e·sin(M) is far off at e = 0.7
+    let m: f64 = 1.0;
+    let e = star_sim::physics::mechanics::kinematics::solve_kepler(m, 0.7);
+    assert!((e - 0.7 * e.sin() - m).abs() < 1e-10);
+    assert!((e - (m + 0.7 * m.sin())).abs() > 0.1);
+
+    for m in [0.0, 0.5, 3.0, 6.0] {
+        let e = star_sim::physics::mechanics::kinematics::solve_kepler(m, 0.99);
+        assert!((e - 0.99 * e.sin() - m).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_state_vector_matches_textbook_ephemeris() {
+    // Vallado, Fundamentals of Astrodynamics, Example 2-6 (Earth satellite)
+    let mu = 3.986_004_418e14;
+    let (p, e): (f64, f64) = (11_067.790e3, 0.83285);
+    let nu = Angle::<Degree>::new(92.335).convert_to::<Radian>().value();
+    let eccentric = 2.0 * (((1.0 - e) / (1.0 + e)).sqrt() * (nu / 2.0).tan()).atan();
+    let orbit = Orbit {
+        semi_major_axis: Distance::<Meter>::new(p / (1.0 - e * e)).convert_to::<AstronomicalUnit>(),
+        eccentricity: e,
+        inclination: Angle::<Degree>::new(87.87).convert_to::<Radian>(),
+        longitude_of_ascending_node: Angle::<Degree>::new(227.89).convert_to::<Radian>(),
+        argument_of_periapsis: Angle::<Degree>::new(53.38).convert_to::<Radian>(),
+        mean_anomaly_at_epoch: Angle::<Radian>::new(eccentric - e * eccentric.sin()),
+    };
+    let mass = Mass::<Kilogram>::new(mu / f64::from(star_sim::physics::constants::G));
+
+    let (position, velocity) = orbit.state_vector(Time::<Second>::new(0.0), mass);
+    let expected_position = [6525.344, 6861.535, 6449.125];
+    let expected_velocity = [4.902276, 5.533124, -1.975709];
+    for axis in 0..3 {
+        let km = position[axis].convert_to::<Kilometer>().value();
+        assert!(
+            (km - expected_position[axis]).abs() < 0.05,
+            "r[{axis}] = {km}"
+        );
+        let km_s = velocity[axis].value() / 1000.0;
+        assert!(
+            (km_s - expected_velocity[axis]).abs() < 1e-4,
+            "v[{axis}] = {km_s}"
+        );
+    }
+}
+
+#[test]
+fn test_state_vector_round_trip() {
+    let mass = Mass::<SolarMass>::new(1.3);
+    let angle_diff = |a: f64, b: f64| {
+        let d = (a - b).rem_euclid(std::f64::consts::TAU);
+        d.min(std::f64::consts::TAU - d)
+    };
+    let orbits = [
+        Orbit {
+            semi_major_axis: Distance::<AstronomicalUnit>::new(2.5),
+            eccentricity: 0.3,
+            inclination: Angle::<Radian>::new(0.4),
+            longitude_of_ascending_node: Angle::<Radian>::new(1.2),
+            argument_of_periapsis: Angle::<Radian>::new(4.0),
+            mean_anomaly_at_epoch: Angle::<Radian>::new(2.2),
+        },
+        Orbit {
+            semi_major_axis: Distance::<AstronomicalUnit>::new(0.05),
+            eccentricity: 0.9,
+            inclination: Angle::<Radian>::new(2.6),
+            longitude_of_ascending_node: Angle::<Radian>::new(5.5),
+            argument_of_periapsis: Angle::<Radian>::new(0.3),
+            mean_anomaly_at_epoch: Angle::<Radian>::new(5.9),
+        },
+        // Equatorial: the node falls back to zero, ω is measured from the x axis
+        Orbit {
+            eccentricity: 0.2,
+            argument_of_periapsis: Angle::<Radian>::new(1.0),
+            mean_anomaly_at_epoch: Angle::<Radian>::new(0.5),
+            ..Default::default()
+        },
+        // Circular: ω falls back to zero, the anomaly is measured from the node
+        Orbit {
+            inclination: Angle::<Radian>::new(0.7),
+            longitude_of_ascending_node: Angle::<Radian>::new(2.0),
+            mean_anomaly_at_epoch: Angle::<Radian>::new(3.0),
+            ..Default::default()
+        },
+    ];
+
+    for original in orbits {
+        let (position, velocity) = original.state_vector(Time::<Second>::new(0.0), mass);
+        let recovered = Orbit::from_state_vector(position, velocity, mass);
+
+        let a = original.semi_major_axis.value();
+        assert!((recovered.semi_major_axis.value() - a).abs() < 1e-6 * a);
+        assert!((recovered.eccentricity - original.eccentricity).abs() < 1e-6);
+        assert!(angle_diff(recovered.inclination.value(), original.inclination.value()) < 1e-6);
+        assert!(
+            angle_diff(
+                recovered.longitude_of_ascending_node.value(),
+                original.longitude_of_ascending_node.value()
+            ) < 1e-6
+        );
+        assert!(
+            angle_diff(
+                recovered.argument_of_periapsis.value(),
+                original.argument_of_periapsis.value()
+            ) < 1e-6
+        );
+        assert!(
+            angle_diff(
+                recovered.mean_anomaly_at_epoch.value(),
+                original.mean_anomaly_at_epoch.value()
+            ) < 1e-6
+        );
+    }
+}
+
+#[test]
+fn test_snow_line() {
+    let sun = sun();
+    let snow_line = sun.snow_line().value();
+    assert!(snow_line > 2.5 && snow_line < 3.0);
+
+    let water = sun
+        .frost_line_for_species(Temperature::<Kelvin>::new(170.0))
+        .value();
+    assert!((water - snow_line).abs() < 0.1);
+    // Carbon monoxide freezes out far beyond the water snow line
+    let co = sun
+        .frost_line_for_species(Temperature::<Kelvin>::new(25.0))
+        .value();
+    assert!(co > 100.0);
+
+    let dim = star(0.3, SpectralType::M(3), LuminosityClass::V);
+    let dim = StarData {
+        luminosity: Power::<SolarLuminosity>::new(0.01),
+        ..dim
+    };
+    assert!((dim.snow_line().value() - 0.27).abs() < 1e-9);
+}
+
+#[test]
+fn test_tidal_heating_rises_sharply_close_in() {
+    let planet = earth();
+    let host = Mass::<SolarMass>::new(0.2);
+    let flux_at = |au: f64| {
+        let orbit = Orbit {
+            semi_major_axis: Distance::<AstronomicalUnit>::new(au),
+            eccentricity: 0.05,
+            ..Default::default()
+        };
+        planet.tidal_heating_flux(host, &orbit)
+    };
+
+    // Flux ∝ a^-7.5: halving the distance raises it by a factor of ~180
+    assert!(flux_at(0.025) / flux_at(0.05) > 150.0);
+    assert!(flux_at(0.01) > 2.0, "Io-like roasting: {}", flux_at(0.01));
+    assert!(flux_at(0.2) < 0.01);
+    assert_eq!(planet.tidal_heating_flux(host, &Orbit::default()), 0.0);
+}
+
+#[test]
+fn test_sun_like_star_becomes_white_dwarf() {
+    let sun = sun();
+    assert!(sun.white_dwarf_at_age(Time::<Gigayear>::new(4.6)).is_none());
+
+    let remnant = sun.white_dwarf_at_age(Time::<Gigayear>::new(13.0)).unwrap();
+    assert_eq!(remnant.spectral_type, SpectralType::D);
+    assert!(remnant.luminosity.value() < 0.01);
+    assert!(remnant.mass.value() > 0.45 && remnant.mass.value() < 0.65);
+    let radius_earth = remnant.radius.convert_to::<EarthRadius>().value();
+    assert!(
+        radius_earth > 0.8 && radius_earth < 2.0,
+        "{radius_earth} R⊕"
+    );
+
+    let older = sun.white_dwarf_at_age(Time::<Gigayear>::new(20.0)).unwrap();
+    assert!(older.luminosity.value() < remnant.luminosity.value());
+    assert!(older.temperature.value() < remnant.temperature.value());
+
+    let massive = StarData {
+        mass: Mass::<SolarMass>::new(12.0),
+        luminosity: Power::<SolarLuminosity>::new(1.0e4),
+        ..sun
+    };
+    assert!(
+        massive
+            .white_dwarf_at_age(Time::<Gigayear>::new(1.0))
+            .is_none()
+    );
+}
+
+#[test]
+fn test_magnitudes() {
+    let sun = sun();
+    assert!((sun.absolute_bolometric_magnitude() - 4.74).abs() < 1e-9);
+    let from_earth = sun.apparent_magnitude(Distance::<AstronomicalUnit>::new(1.0));
+    assert!((from_earth + 26.8).abs() < 0.1, "{from_earth}");
+    let at_ten_parsecs = sun.apparent_magnitude(Distance::<Parsec>::new(10.0));
+    assert!((at_ten_parsecs - 4.74).abs() < 1e-9);
+
+    let dark = StarData {
+        luminosity: Power::<SolarLuminosity>::new(0.0),
+        ..sun
+    };
+    assert_eq!(dark.absolute_bolometric_magnitude(), f64::INFINITY);
+    assert_eq!(
+        dark.apparent_magnitude(Distance::<Parsec>::new(1.0)),
+        f64::INFINITY
+    );
+}
+
+#[test]
+fn test_color_index() {
+    let with_temperature = |kelvin: f64| StarData {
+        temperature: Temperature::<Kelvin>::new(kelvin),
+        ..sun()
+    };
+    assert!((sun().color_index_bv() - 0.65).abs() < 0.02);
+
+    let temperatures = [40_000.0, 10_000.0, 7_000.0, 5_778.0, 4_500.0, 3_200.0];
+    let indices: Vec<f64> = temperatures
+        .iter()
+        .map(|&t| with_temperature(t).color_index_bv())
+        .collect();
+    assert!(indices.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(indices[0] < -0.2);
+    assert!(indices[5] > 1.4);
+
+    let (r, _, b) = with_temperature(40_000.0).rgb_approximation();
+    assert!(b > r);
+    let (r, _, b) = with_temperature(3_200.0).rgb_approximation();
+    assert!(r > b);
+}
+
+#[test]
+fn test_bolometric_correction() {
+    let sun = sun();
+    assert!((sun.bolometric_correction() + 0.07).abs() < 0.02);
+    let visual = sun.visual_luminosity().value();
+    assert!(visual < sun.luminosity.value() && visual > 0.9);
+
+    // An O star radiates most of its output in the ultraviolet
+    let o_star = StarData {
+        temperature: Temperature::<Kelvin>::new(40_000.0),
+        ..sun
+    };
+    assert!(o_star.bolometric_correction() < -3.0);
+    assert!(o_star.visual_luminosity().value() < 0.1 * o_star.luminosity.value());
+}
+
+#[test]
+fn test_angular_radius() {
+    // The Sun spans about 32 arcminutes as seen from Earth
+    let radius = sun().angular_radius(Distance::<AstronomicalUnit>::new(1.0));
+    let diameter_arcmin = 2.0 * radius.convert_to::<Degree>().value() * 60.0;
+    assert!((diameter_arcmin - 32.0).abs() < 0.2, "{diameter_arcmin}");
+}
+
+#[test]
+fn test_blackbody_sed() {
+    let sun = sun();
+    let wavelengths: Vec<f64> = (100..=3000).map(f64::from).collect();
+    let sed = sun.sed(&wavelengths);
+    let peak = (0..sed.len())
+        .max_by(|&a, &b| sed[a].total_cmp(&sed[b]))
+        .map(|index| wavelengths[index])
+        .unwrap();
+    // Wien's law: λ_max = 2.898e6 nm·K / T
+    assert!((peak - 2.898e6 / 5778.0).abs() < 2.0, "{peak}");
+
+    // The spectrum integrates to the bolometric luminosity
+    let integral: f64 = (10..200_000)
+        .map(|nm| sun.blackbody_flux(f64::from(nm)))
+        .sum();
+    let luminosity = sun.luminosity.convert_to::<Watt>().value();
+    assert!((integral / luminosity - 1.0).abs() < 0.01);
+}
+
+#[test]
+fn test_earth_mars_synodic_period() {
+    let sun = Mass::<SolarMass>::new(1.0);
+    let earth = Orbit::default();
+    let mars = Orbit {
+        semi_major_axis: Distance::<AstronomicalUnit>::new(1.524),
+        ..Default::default()
+    };
+    let synodic = earth
+        .synodic_period(&mars, sun)
+        .convert_to::<Year>()
+        .value();
+    assert!((synodic - 2.14).abs() < 0.02, "{synodic} yr");
+    assert_eq!(
+        mars.synodic_period(&earth, sun).value(),
+        earth.synodic_period(&mars, sun).value()
+    );
+    assert!(earth.synodic_period(&earth, sun).value().is_infinite());
+}
+
+fn planet_at(name: &str, semi_major_axis: Distance<AstronomicalUnit>) -> SerializableBody {
+    SerializableBody {
+        name: name.to_string(),
+        kind: BodyKind::Planet(earth()),
+        orbit: Some(Orbit {
+            semi_major_axis,
+            ..Default::default()
+        }),
+        satellites: vec![],
+    }
+}
+
+#[test]
+fn test_detect_resonances() {
+    let axes = PlanetSpacing::ResonanceChain(vec![(2, 1)])
+        .semi_major_axes(Distance::<AstronomicalUnit>::new(0.3), 2);
+    let star = SerializableBody {
+        name: "Star".to_string(),
+        kind: BodyKind::Star(sun()),
+        orbit: None,
+        // Listed out of order: detection sorts by semi-major axis
+        satellites: vec![
+            planet_at("Far", Distance::<AstronomicalUnit>::new(3.0)),
+            planet_at("Outer", axes[1]),
+            planet_at("Inner", axes[0]),
+        ],
+    };
+    assert_eq!(star.detect_resonances(), vec![(2, 1, 2, 1)]);
+
+    let spread = SerializableBody {
+        satellites: vec![
+            planet_at("b", Distance::<AstronomicalUnit>::new(1.0)),
+            planet_at("c", Distance::<AstronomicalUnit>::new(1.7)),
+        ],
+        ..star
+    };
+    assert!(spread.detect_resonances().is_empty());
+}
+
+#[test]
+fn test_continuously_habitable_zone() {
+    let sun = sun();
+    // Faint young Sun: about 70 % of today's luminosity
+    let young = sun.luminosity_at_age(Time::<Gigayear>::new(0.0)).value();
+    assert!((young - 0.714).abs() < 0.01);
+    assert!((sun.luminosity_at_age(Time::<Gigayear>::new(4.6)).value() - 1.0).abs() < 1e-9);
+
+    let chz =
+        sun.continuously_habitable_zone(Time::<Gigayear>::new(0.0), Time::<Gigayear>::new(4.6));
+    assert!(!chz.is_empty());
+    assert!(chz.width().value() > 0.1);
+    assert!(chz.contains(Distance::<AstronomicalUnit>::new(1.0)));
+    // Narrower than the instantaneous zone today
+    assert!(chz.width().value() < sun.habitable_zone().width().value());
+
+    // A 3 M☉ star brightens through its short main sequence, leaving no CHZ
+    let bright = StarData {
+        mass: Mass::<SolarMass>::new(3.0),
+        luminosity: Power::<SolarLuminosity>::new(f64::powf(3.0, 3.5)),
+        ..sun
+    };
+    let collapsed =
+        bright.continuously_habitable_zone(Time::<Gigayear>::new(0.0), Time::<Gigayear>::new(0.6));
+    assert!(collapsed.is_empty());
+    assert_eq!(collapsed.inner_edge.value(), collapsed.outer_edge.value());
+}
+
+#[test]
+fn test_csv_export() {
+    let system = binary(sun(), star(0.5, SpectralType::M(0), LuminosityClass::V));
+    let csv = system.to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(
+        lines[0],
+        "name,parent,kind,mass_solar,semi_major_axis_au,eccentricity,luminosity_solar"
+    );
+    assert_eq!(lines.len(), 3);
+    assert!(lines[1].starts_with("A,,star,"));
+    assert!(lines[2].starts_with("B,A,star,0.5"));
+    assert!(lines[2].contains(",20,"));
+
+    let mut teacup = generate_teacup_system();
+    teacup.roots[0].name = "Teacup, A".to_string();
+    let csv = teacup.to_csv();
+    assert!(csv.contains("\"Teacup, A\",,star"));
+    assert!(csv.contains("Teacup Ae II,Teacup Ae,planet"));
+}
+
+#[test]
+fn test_dot_export_of_triple() {
+    let mut system = binary(sun(), star(0.8, SpectralType::K(2), LuminosityClass::V));
+    system.roots[0].satellites[0]
+        .satellites
+        .push(SerializableBody {
+            name: "C".to_string(),
+            kind: BodyKind::Star(star(0.2, SpectralType::M(4), LuminosityClass::V)),
+            orbit: Some(Orbit {
+                semi_major_axis: Distance::<AstronomicalUnit>::new(0.5),
+                eccentricity: 0.12,
+                ..Default::default()
+            }),
+            satellites: vec![],
+        });
+    // A Jupiter next to B: its period depends on A alone, not on the whole triple
+    system.roots[0].satellites.push(SerializableBody {
+        name: "A b".to_string(),
+        kind: BodyKind::Planet(PlanetData {
+            mass: Mass::<EarthMass>::new(317.8),
+            ..earth()
+        }),
+        orbit: Some(Orbit {
+            semi_major_axis: Distance::<AstronomicalUnit>::new(1.0),
+            ..Default::default()
+        }),
+        satellites: vec![],
+    });
+
+    let dot = system.to_dot();
+    assert!(dot.starts_with("digraph \"Binary\" {"));
+    assert!(dot.trim_end().ends_with('}'));
+    assert_eq!(dot.matches("shape=doublecircle").count(), 3);
+    assert_eq!(dot.matches(" -> ").count(), 3);
+    assert!(dot.contains("n0 [label=\"A\\nG2V\\n1.00 M☉\""));
+    assert!(dot.contains("e = 0.12"));
+
+    let period = |edge: &str| -> f64 {
+        let label = dot
+            .lines()
+            .find(|line| line.trim_start().starts_with(edge))
+            .unwrap_or_else(|| panic!("no edge {edge}"));
+        let start = label.find("P = ").unwrap() + 4;
+        let end = label[start..].find(" d").unwrap() + start;
+        label[start..end].parse().unwrap()
+    };
+    // P = 365.25 d · √(a³ / M) with M = central body plus orbiting subtree
+    let expected = |a: f64, mass: f64| 365.25 * (a.powi(3) / mass).sqrt();
+    for (edge, days) in [
+        ("n0 -> n1 ", expected(20.0, 2.0)),
+        ("n1 -> n2 ", expected(0.5, 1.0)),
+        ("n0 -> n3 ", expected(1.0, 1.0 + 317.8 / 332_946.0)),
+    ] {
+        let actual = period(edge);
+        assert!(
+            (actual / days - 1.0).abs() < 1e-3,
+            "{edge}: {actual} d, expected {days} d"
+        );
+    }
+}
+
+#[test]
+fn test_gyrochronology() {
+    let sun = sun();
+    let today = sun
+        .rotation_period_at_age(Time::<Gigayear>::new(4.6))
+        .value();
+    assert!((today - 25.4).abs() < 0.5);
+    let age = sun
+        .gyrochronology_age(Time::<Day>::new(25.4))
+        .unwrap()
+        .value();
+    assert!((age - 4.6).abs() < 0.01);
+
+    // Pre-main-sequence stars spin fast
+    assert!(
+        sun.rotation_period_at_age(Time::<Gigayear>::new(0.01))
+            .value()
+            < 2.0
+    );
+    let m_dwarf = star(0.2, SpectralType::M(4), LuminosityClass::V);
+    assert!(
+        m_dwarf
+            .rotation_period_at_age(Time::<Gigayear>::new(0.2))
+            .value()
+            < 2.0
+    );
+
+    let k_dwarf = star(0.7, SpectralType::K(5), LuminosityClass::V);
+    for age in [0.5, 2.0, 8.0] {
+        let period = k_dwarf.rotation_period_at_age(Time::<Gigayear>::new(age));
+        let recovered = k_dwarf.gyrochronology_age(period).unwrap().value();
+        assert!((recovered - age).abs() < 1e-9 * age.max(1.0));
+    }
+
+    let a_star = star(2.0, SpectralType::A(0), LuminosityClass::V);
+    assert!(a_star.gyrochronology_age(Time::<Day>::new(1.5)).is_none());
+}
+
+#[test]
+fn test_tisserand_parameter() {
+    let jupiter_a = Distance::<AstronomicalUnit>::new(5.2);
+    let trojan = Orbit {
+        semi_major_axis: jupiter_a,
+        eccentricity: 0.05,
+        inclination: Angle::<Degree>::new(5.0).convert_to::<Radian>(),
+        ..Orbit::default()
+    };
+    assert!((trojan.tisserand_parameter(jupiter_a) - 3.0).abs() < 0.02);
+
+    // The exact co-orbital circular case is T = 3
+    let circular = Orbit {
+        semi_major_axis: jupiter_a,
+        ..Orbit::default()
+    };
+    let jupiter_km = jupiter_a.convert_to::<Kilometer>();
+    assert!((circular.tisserand_parameter(jupiter_km) - 3.0).abs() < 1e-9);
+
+    // A Jupiter-family comet sits below 3
+    let comet = Orbit {
+        semi_major_axis: Distance::<AstronomicalUnit>::new(3.5),
+        eccentricity: 0.6,
+        inclination: Angle::<Degree>::new(10.0).convert_to::<Radian>(),
+        ..Orbit::default()
+    };
+    let t = comet.tisserand_parameter(jupiter_a);
+    assert!(t > 2.0 && t < 3.0, "{t}");
+}
+
+#[test]
+fn test_two_body_integration_conserves_energy() {
+    let system = binary(sun(), star(0.5, SpectralType::M(0), LuminosityClass::V));
+    let orbit = system.roots[0].satellites[0].orbit.unwrap();
+    let period = orbit.orbital_period(Mass::<SolarMass>::new(1.5));
+
+    let snapshots = system.integrate(1000, period / 1000.0);
+    assert_eq!(snapshots.len(), 1001);
+    assert!((snapshots[1000].time.value() / period.value() - 1.0).abs() < 1e-9);
+
+    let initial = snapshots[0].total_energy.value();
+    assert!(initial < 0.0);
+    for snapshot in &snapshots {
+        assert!((snapshot.total_energy.value() / initial - 1.0).abs() < 0.01);
+    }
+
+    // The circular orbit keeps its separation and closes after one period
+    let separation = |snapshot: &Snapshot| {
+        let [a, b] = [&snapshot.positions[0], &snapshot.positions[1]];
+        let distance = (0..3)
+            .map(|k| (a[k] - b[k]).value().powi(2))
+            .sum::<f64>()
+            .sqrt();
+        Distance::<Meter>::new(distance)
+            .convert_to::<AstronomicalUnit>()
+            .value()
+    };
+    assert!(
+        snapshots
+            .iter()
+            .all(|snapshot| (separation(snapshot) - 20.0).abs() < 0.2)
+    );
+    let drift = (0..3)
+        .map(|k| {
+            (snapshots[1000].positions[1][k] - snapshots[0].positions[1][k])
+                .value()
+                .powi(2)
+        })
+        .sum::<f64>()
+        .sqrt();
+    let au = Distance::<AstronomicalUnit>::new(1.0)
+        .convert_to::<Meter>()
+        .value();
+    assert!(drift < 0.5 * au, "{}", drift / au);
+}
+
+#[test]
+fn test_conserved_quantities_of_circular_binary() {
+    let system = binary(sun(), star(0.5, SpectralType::M(0), LuminosityClass::V));
+    let g = f64::from(star_sim::physics::constants::G);
+    let m1 = Mass::<SolarMass>::new(1.0).convert_to::<Kilogram>().value();
+    let m2 = Mass::<SolarMass>::new(0.5).convert_to::<Kilogram>().value();
+    let a = Distance::<AstronomicalUnit>::new(20.0)
+        .convert_to::<Meter>()
+        .value();
+
+    let energy = system.total_energy().value();
+    assert!(energy < 0.0);
+    let expected = -g * m1 * m2 / (2.0 * a);
+    assert!(
+        (energy / expected - 1.0).abs() < 1e-6,
+        "{energy} vs {expected}"
+    );
+
+    // L = μ·√(G·M·a) with the reduced mass μ
+    let reduced_mass = m1 * m2 / (m1 + m2);
+    let expected = reduced_mass * (g * (m1 + m2) * a).sqrt();
+    let momentum = system.total_angular_momentum().value();
+    assert!((momentum / expected - 1.0).abs() < 1e-6);
+
+    // The integrator keeps the energy
+    let last = system.integrate(200, Time::<Year>::new(0.2)).pop().unwrap();
+    assert!((last.total_energy.value() / energy - 1.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_unbound_orbits() {
+    let mass = Mass::<SolarMass>::new(1.0);
+    let hyperbolic = Orbit {
+        semi_major_axis: Distance::<AstronomicalUnit>::new(-2.0),
+        eccentricity: 1.5,
+        ..Default::default()
+    };
+    assert!(hyperbolic.orbital_period(mass).value().is_infinite());
+    let parabolic = Orbit {
+        eccentricity: 1.0,
+        ..Default::default()
+    };
+    assert!(parabolic.orbital_period(mass).value().is_infinite());
+    assert_eq!(
+        parabolic
+            .hyperbolic_excess_velocity(mass)
+            .map(|v| v.value()),
+        Some(0.0)
+    );
+
+    // v∞ = √(GM/|a|): Earth's orbital speed over √2 for |a| = 2 AU
+    let excess = hyperbolic.hyperbolic_excess_velocity(mass).unwrap();
+    assert!((excess.value() / (29_780.0 / 2f64.sqrt()) - 1.0).abs() < 0.01);
+
+    // Bound orbits are unchanged and have no excess velocity
+    let earth_orbit = Orbit::default();
+    assert!(earth_orbit.hyperbolic_excess_velocity(mass).is_none());
+    let year = earth_orbit.orbital_period(mass).convert_to::<Day>();
+    assert!((year.value() - 365.25).abs() < 1.0);
+}
+
+#[test]
+fn test_hyperbolic_state_vector() {
+    let mass = Mass::<SolarMass>::new(1.0);
+    let mu = f64::from(G) * mass.convert_to::<Kilogram>().value();
+    let hyperbolic = Orbit {
+        semi_major_axis: Distance::<AstronomicalUnit>::new(-2.0),
+        eccentricity: 1.5,
+        inclination: Angle::<Radian>::new(0.3),
+        ..Default::default()
+    };
+    let a = hyperbolic.semi_major_axis.convert_to::<Meter>().value();
+    let norm = |v: [f64; 3]| v.iter().map(|x| x * x).sum::<f64>().sqrt();
+
+    // At the epoch the body is at periapsis, q = |a|·(e − 1) = 1 AU
+    let (r, _) = hyperbolic.state_vector(Time::<Second>::new(0.0), mass);
+    let q = norm(r.map(|x| x.convert_to::<AstronomicalUnit>().value()));
+    assert!((q - 1.0).abs() < 1e-9);
+
+    let max_true_anomaly = (-1.0 / hyperbolic.eccentricity).acos();
+    let mut previous = 0.0;
+    for days in [-200.0, -30.0, 10.0, 100.0, 1000.0, 10_000.0] {
+        let time = Time::<Day>::new(days);
+        let (r, v) = hyperbolic.state_vector(time, mass);
+        let (r, v) = (r.map(|x| x.value()), v.map(|x| x.value()));
+        assert!(r.iter().chain(&v).all(|x| x.is_finite()), "t = {days} d");
+
+        // Vis-viva: the specific energy is μ/(2|a|) everywhere on the branch
+        let energy = norm(v).powi(2) / 2.0 - mu / norm(r);
+        assert!((energy / (mu / (2.0 * a.abs())) - 1.0).abs() < 1e-6);
+
+        let nu = hyperbolic.true_anomaly_at_time(time, mass).value();
+        assert!(nu.abs() < max_true_anomaly);
+        if days > 0.0 {
+            assert!(nu > previous);
+            previous = nu;
+        }
+
+        let recovered = Orbit::from_state_vector(
+            r.map(Distance::<Meter>::new),
+            v.map(Velocity::<MeterPerSecond>::new),
+            mass,
+        );
+        assert!((recovered.eccentricity - 1.5).abs() < 1e-9);
+        assert!((recovered.semi_major_axis.value() / -2.0 - 1.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_habitable_zone_window() {
+    let sun = sun();
+    let (entry, exit) = sun
+        .habitable_zone_window(Distance::<AstronomicalUnit>::new(1.0))
+        .unwrap();
+    let lifetime = sun.main_sequence_lifetime().value();
+    assert_eq!(entry.value(), 0.0);
+    // The brightening Sun pushes the inner edge past 1 AU after ~5.6 Gyr
+    assert!(
+        exit.value() > 0.5 * lifetime && exit.value() < 0.7 * lifetime,
+        "{exit}"
+    );
+
+    // At 1.5 AU a planet only enters as the Sun brightens; Mercury never does
+    let (outer_entry, outer_exit) = sun
+        .habitable_zone_window(Distance::<AstronomicalUnit>::new(1.5))
+        .unwrap();
+    assert!(outer_entry.value() > 0.0);
+    assert!((outer_exit.value() - lifetime).abs() < 1e-9);
+    assert!(
+        sun.habitable_zone_window(Distance::<AstronomicalUnit>::new(0.39))
+            .is_none()
+    );
+}
+
+#[test]
+fn test_star_from_spectral_type() {
+    let star = StarData::from_spectral_type(
+        SpectralType::G(2),
+        LuminosityClass::V,
+        Time::<Gigayear>::new(4.6),
+    );
+    assert!((star.mass.value() - 1.0).abs() < 0.05, "{}", star.mass);
+    assert!(
+        (star.temperature.value() - 5800.0).abs() < 150.0,
+        "{}",
+        star.temperature
+    );
+    assert_eq!(star.spectral_type, SpectralType::G(2));
+
+    // Earlier types are more massive and hotter, white dwarfs are compact
+    let a0 = StarData::from_spectral_type(
+        SpectralType::A(0),
+        LuminosityClass::V,
+        Time::<Gigayear>::new(0.1),
+    );
+    assert!(a0.mass.value() > 2.0 && a0.temperature.value() > 8000.0);
+    let white_dwarf = StarData::from_spectral_type(
+        SpectralType::D,
+        LuminosityClass::VII,
+        Time::<Gigayear>::new(1.0),
+    );
+    assert!(white_dwarf.radius.value() < 0.02);
+}
+
+#[test]
+fn test_spectral_type_display() {
+    let cases = [
+        (SpectralType::O(5), "O5"),
+        (SpectralType::B(0), "B0"),
+        (SpectralType::A(1), "A1"),
+        (SpectralType::F(7), "F7"),
+        (SpectralType::G(2), "G2"),
+        (SpectralType::K(5), "K5"),
+        (SpectralType::M(9), "M9"),
+        (SpectralType::L, "L"),
+        (SpectralType::T, "T"),
+        (SpectralType::Y, "Y"),
+        (SpectralType::D, "D"),
+        (SpectralType::WR, "WR"),
+    ];
+    for (spectral_type, expected) in cases {
+        assert_eq!(spectral_type.to_string(), expected);
+    }
+
+    assert_eq!(LuminosityClass::Ia.to_string(), "Ia");
+    assert_eq!(LuminosityClass::III.to_string(), "III");
+    assert_eq!(LuminosityClass::VII.to_string(), "VII");
+
+    assert_eq!(sun().spectral_classification(), "G2V");
+    let giant = star(1.2, SpectralType::K(1), LuminosityClass::III);
+    assert_eq!(giant.spectral_classification(), "K1III");
+    let white_dwarf = star(0.6, SpectralType::D, LuminosityClass::VII);
+    assert_eq!(white_dwarf.spectral_classification(), "D");
+    let wolf_rayet = StarData::from_spectral_type(
+        SpectralType::WR,
+        LuminosityClass::Ia,
+        Time::<Gigayear>::new(0.005),
+    );
+    assert_eq!(wolf_rayet.spectral_classification(), "WR");
+    assert!(wolf_rayet.temperature.value() > 50_000.0);
+}
+
+#[test]
+fn test_parse_spectral_type() {
+    assert_eq!("K5".parse::<SpectralType>(), Ok(SpectralType::K(5)));
+    assert_eq!("D".parse::<SpectralType>(), Ok(SpectralType::D));
+    assert_eq!("WR".parse::<SpectralType>(), Ok(SpectralType::WR));
+
+    let all = [
+        SpectralType::O(3),
+        SpectralType::B(0),
+        SpectralType::A(9),
+        SpectralType::F(5),
+        SpectralType::G(2),
+        SpectralType::K(7),
+        SpectralType::M(4),
+        SpectralType::L,
+        SpectralType::T,
+        SpectralType::Y,
+        SpectralType::D,
+        SpectralType::WR,
+    ];
+    for spectral_type in all {
+        assert_eq!(
+            spectral_type.to_string().parse::<SpectralType>(),
+            Ok(spectral_type)
+        );
+    }
+
+    assert_eq!(
+        "Z3".parse::<SpectralType>(),
+        Err(ParseSpectralTypeError::UnknownClass("Z3".to_string()))
+    );
+    assert_eq!(
+        "G12".parse::<SpectralType>(),
+        Err(ParseSpectralTypeError::InvalidSubclass("G12".to_string()))
+    );
+    assert!("G".parse::<SpectralType>().is_err());
+    assert!("".parse::<SpectralType>().is_err());
+}
+
+#[test]
+fn test_atmospheric_escape() {
+    let age = Time::<Gigayear>::new(1.0);
+    let earth_analog =
+        earth().atmospheric_escape(&sun(), Distance::<AstronomicalUnit>::new(1.0), age);
+    // Oxygen is far too heavy to escape thermally from Earth
+    assert!(earth_analog.jeans.value() < 1e-6 * earth_analog.hydrodynamic.value());
+
+    let m_dwarf = StarData {
+        mass: Mass::<SolarMass>::new(0.3),
+        radius: Distance::<SunRadius>::new(0.3),
+        temperature: Temperature::<Kelvin>::new(3400.0),
+        luminosity: Power::<SolarLuminosity>::new(0.01),
+        spectral_type: SpectralType::M(4),
+        luminosity_class: LuminosityClass::V,
+    };
+    let close_in =
+        earth().atmospheric_escape(&m_dwarf, Distance::<AstronomicalUnit>::new(0.03), age);
+    assert!(close_in.total().value() > 20.0 * earth_analog.total().value());
+}
+
+#[test]
+fn test_jeans_escape() {
+    let age = Time::<Gigayear>::new(1.0);
+    let at_1_au = earth()
+        .atmospheric_escape(&sun(), Distance::<AstronomicalUnit>::new(1.0), age)
+        .jeans
+        .value();
+    // λ ≈ 120 at a 1000 K exobase: oxygen is bound for good
+    assert!(at_1_au < 1e-30, "Earth Jeans escape {at_1_au:e} kg/s");
+
+    // A hot Mars-sized planet has λ ≈ 7 and loses oxygen thermally
+    let mars_like = PlanetData {
+        mass: Mass::<EarthMass>::new(0.107),
+        radius: Distance::<EarthRadius>::new(0.532),
+        ..earth()
+    };
+    let hot_mars = mars_like
+        .atmospheric_escape(&sun(), Distance::<AstronomicalUnit>::new(0.1), age)
+        .jeans
+        .value();
+    assert!(hot_mars > 1.0, "hot Mars Jeans escape {hot_mars:e} kg/s");
+
+    // The exobase temperature, and with it the Jeans flux, rises towards the star
+    let closer = earth()
+        .atmospheric_escape(&sun(), Distance::<AstronomicalUnit>::new(0.5), age)
+        .jeans
+        .value();
+    assert!(closer > 1e6 * at_1_au);
+}
+
+#[test]
+fn test_hydrodynamic_escape() {
+    let age = Time::<Gigayear>::new(1.0);
+    let hydrodynamic = |planet: &PlanetData, distance_au: f64| {
+        planet
+            .atmospheric_escape(&sun(), Distance::<AstronomicalUnit>::new(distance_au), age)
+            .hydrodynamic
+            .value()
+    };
+
+    // Energy-limited hydrogen loss of a 1 Gyr old Earth: about 10⁴ kg/s
+    let at_1_au = hydrodynamic(&earth(), 1.0);
+    assert!(
+        (1e3..1e5).contains(&at_1_au),
+        "hydrodynamic {at_1_au:e} kg/s"
+    );
+
+    // Ṁ ∝ F_XUV ∝ 1/d²
+    let ratio = hydrodynamic(&earth(), 0.5) / at_1_au;
+    assert!((ratio - 4.0).abs() < 1e-9, "distance ratio {ratio}");
+
+    // Ṁ ∝ R³/M: doubling the radius at fixed mass loses eight times as much
+    let puffy = PlanetData {
+        radius: Distance::<EarthRadius>::new(2.0),
+        ..earth()
+    };
+    let ratio = hydrodynamic(&puffy, 1.0) / at_1_au;
+    assert!((ratio - 8.0).abs() < 1e-9, "radius ratio {ratio}");
+
+    // A younger, more active Sun drives stronger escape
+    let young = earth()
+        .atmospheric_escape(
+            &sun(),
+            Distance::<AstronomicalUnit>::new(1.0),
+            Time::<Gigayear>::new(0.1),
+        )
+        .hydrodynamic
+        .value();
+    assert!(young > at_1_au);
+}
+
+#[test]
+fn test_initial_mass_function_slope() {
+    let mut rng = ChaCha8Rng::seed_from_u64(2024);
+    let (min, max) = (Mass::<SolarMass>::new(1.0), Mass::<SolarMass>::new(100.0));
+    let masses: Vec<f64> = (0..10_000)
+        .map(|_| {
+            InitialMassFunction::Salpeter
+                .sample(min, max, &mut rng)
+                .value()
+        })
+        .collect();
+    assert!(masses.iter().all(|&m| (1.0..=100.0).contains(&m)));
+
+    // Maximum-likelihood power-law index α = 1 + n / Σ ln(m / m_min)
+    let alpha = 1.0 + masses.len() as f64 / masses.iter().map(|m| m.ln()).sum::<f64>();
+    assert!((alpha - 2.35).abs() < 0.1, "alpha {alpha}");
+
+    // Kroupa and Chabrier turn over at low masses: most stars are M dwarfs
+    let low = Mass::<SolarMass>::new(0.01);
+    for imf in [InitialMassFunction::Kroupa, InitialMassFunction::Chabrier] {
+        let above_sun = (0..10_000)
+            .filter(|_| imf.sample(low, max, &mut rng).value() > 1.0)
+            .count();
+        assert!(above_sun < 1_000, "{imf:?}: {above_sun}");
+    }
+}
+
+#[test]
+fn test_binary_distribution() {
+    let mut rng = ChaCha8Rng::seed_from_u64(5);
+    let twins = BinaryDistribution {
+        mass_ratio_exponent: 4.0,
+        ..BinaryDistribution::for_primary_mass(Mass::<SolarMass>::new(1.0))
+    };
+    let ratios: Vec<f64> = (0..10_000)
+        .map(|_| twins.sample_mass_ratio(&mut rng))
+        .collect();
+    assert!(ratios.iter().all(|&q| (0.1..=1.0).contains(&q)));
+    let mut histogram = [0usize; 10];
+    for q in &ratios {
+        histogram[((q * 10.0) as usize).min(9)] += 1;
+    }
+    let peak = (0..10).max_by_key(|&bin| histogram[bin]).unwrap();
+    assert_eq!(peak, 9, "{histogram:?}");
+
+    // Solar-type binaries: median period near 10^5 days (Raghavan et al. 2010)
+    let solar = BinaryDistribution::for_primary_mass(Mass::<SolarMass>::new(1.0));
+    let mut log_periods: Vec<f64> = (0..10_000)
+        .map(|_| solar.sample_period(&mut rng).value().log10())
+        .collect();
+    log_periods.sort_by(f64::total_cmp);
+    assert!((log_periods[5_000] - 5.03).abs() < 0.1);
+
+    // O stars have much closer companions
+    let massive = BinaryDistribution::for_primary_mass(Mass::<SolarMass>::new(30.0));
+    assert!(massive.log_period_mean < solar.log_period_mean - 2.0);
+}
+
+#[test]
+fn test_asteroid_belt_avoids_planets() {
+    let jupiter_orbit = Orbit {
+        semi_major_axis: Distance::<AstronomicalUnit>::new(5.2),
+        eccentricity: 0.05,
+        ..Default::default()
+    };
+    let system = SerializableStellarSystem {
+        schema_version: SerializableStellarSystem::SCHEMA_VERSION,
+        name: "Sol".to_string(),
+        age: Time::<Gigayear>::new(4.6),
+        roots: vec![SerializableBody {
+            name: "Sun".to_string(),
+            kind: BodyKind::Star(sun()),
+            orbit: None,
+            satellites: vec![SerializableBody {
+                name: "Jupiter".to_string(),
+                kind: BodyKind::Planet(PlanetData {
+                    body_type: BodyType::GasGiant,
+                    mass: Mass::<EarthMass>::new(317.8),
+                    radius: Distance::<EarthRadius>::new(11.2),
+                    ..earth()
+                }),
+                orbit: Some(jupiter_orbit),
+                satellites: vec![],
+            }],
+        }],
+        debris_disk: None,
+    };
+
+    let mut rng = ChaCha8Rng::seed_from_u64(3);
+    let belt = system.generate_asteroid_belt(
+        Distance::<AstronomicalUnit>::new(2.0),
+        Distance::<AstronomicalUnit>::new(8.0),
+        500,
+        &mut rng,
+    );
+    assert_eq!(belt.len(), 500);
+
+    let hill_radius = 5.2 * 0.95 * (317.8 / 332_946.0 / 3.0_f64).cbrt();
+    let kirkwood_3_1 = 5.2 * (1.0_f64 / 3.0).powf(2.0 / 3.0);
+    for orbit in &belt {
+        let a = orbit.semi_major_axis.value();
+        let (periapsis, apoapsis) = (
+            a * (1.0 - orbit.eccentricity),
+            a * (1.0 + orbit.eccentricity),
+        );
+        assert!(apoapsis < 5.2 * 0.95 - hill_radius || periapsis > 5.2 * 1.05 + hill_radius);
+        assert!((a / kirkwood_3_1 - 1.0).abs() > 0.01);
+    }
+}
+
+#[test]
+fn test_asteroid_belt_checks_whole_hierarchy() {
+    let mut rng = ChaCha8Rng::seed_from_u64(5);
+    let au = Distance::<AstronomicalUnit>::new;
+
+    // An inverted range yields no belt instead of panicking
+    let single = binary(sun(), sun());
+    assert!(
+        single
+            .generate_asteroid_belt(au(3.0), au(2.0), 10, &mut rng)
+            .is_empty()
+    );
+
+    // The heavier star B orbits the root A; A still limits B's belt (S-type
+    // limit ~5.9 AU)
+    let inverted = binary(star(0.8, SpectralType::K(2), LuminosityClass::V), sun());
+    assert!(
+        inverted
+            .generate_asteroid_belt(au(10.0), au(15.0), 10, &mut rng)
+            .is_empty()
+    );
+    let inside = inverted.generate_asteroid_belt(au(1.0), au(3.0), 50, &mut rng);
+    assert_eq!(inside.len(), 50);
+
+    // A circumbinary Jupiter around the barycenter clears its orbit in a
+    // circumbinary belt
+    let body = |name: &str, kind, distance| SerializableBody {
+        name: name.to_string(),
+        kind,
+        orbit: Some(Orbit {
+            semi_major_axis: au(distance),
+            ..Default::default()
+        }),
+        satellites: vec![],
+    };
+    let jupiter = PlanetData {
+        body_type: BodyType::GasGiant,
+        mass: Mass::<EarthMass>::new(317.8),
+        radius: Distance::<EarthRadius>::new(11.2),
+        ..earth()
+    };
+    let circumbinary = SerializableStellarSystem {
+        roots: vec![SerializableBody {
+            name: "AB".to_string(),
+            kind: BodyKind::Barycenter,
+            orbit: None,
+            satellites: vec![
+                body("A", BodyKind::Star(sun()), 0.1),
+                body(
+                    "B",
+                    BodyKind::Star(star(0.5, SpectralType::M(0), LuminosityClass::V)),
+                    0.2,
+                ),
+                body("AB b", BodyKind::Planet(jupiter), 3.0),
+            ],
+        }],
+        ..binary(sun(), sun())
+    };
+    let belt = circumbinary.generate_asteroid_belt(au(2.0), au(4.5), 200, &mut rng);
+    assert!(!belt.is_empty());
+    for orbit in &belt {
+        let a = orbit.semi_major_axis.value();
+        let (periapsis, apoapsis) = (
+            a * (1.0 - orbit.eccentricity),
+            a * (1.0 + orbit.eccentricity),
+        );
+        assert!(
+            apoapsis < 2.5 || periapsis > 3.7,
+            "belt orbit at {a} AU crosses the planet"
+        );
+    }
+}
+
+#[test]
+fn test_moon_habitability() {
+    let jupiter = PlanetData {
+        body_type: BodyType::GasGiant,
+        mass: Mass::<EarthMass>::new(317.8),
+        radius: Distance::<EarthRadius>::new(11.2),
+        ..earth()
+    };
+    let europa = PlanetData {
+        body_type: BodyType::IceWorld,
+        mass: Mass::<EarthMass>::new(0.008),
+        radius: Distance::<EarthRadius>::new(0.245),
+        ..earth()
+    };
+    let moon_orbit = Orbit {
+        semi_major_axis: Distance::<Kilometer>::new(671_034.0).convert_to::<AstronomicalUnit>(),
+        eccentricity: 0.009,
+        ..Default::default()
+    };
+
+    // A Europa analog of a Jupiter at 1 AU is temperate rather than frozen
+    let moon = europa.moon_habitability(&sun(), &jupiter, &Orbit::default(), &moon_orbit);
+    assert!((moon.stellar_flux - 1361.0).abs() < 5.0);
+    assert!(moon.planetary_flux > 1.0 && moon.planetary_flux < 10.0);
+    assert!(moon.eclipse_fraction > 0.02 && moon.eclipse_fraction < 0.05);
+    assert!(moon.tidal_heating_flux > 0.01);
+    let temperature = moon.equilibrium_temperature.value();
+    assert!(temperature > 240.0 && temperature < 265.0, "{temperature}");
+}
+
+#[test]
+fn test_albedo_and_greenhouse_temperatures() {
+    let one_au = Distance::<AstronomicalUnit>::new(1.0);
+    let default = PlanetData::equilibrium_temperature(&sun(), one_au, PlanetData::DEFAULT_ALBEDO);
+    assert!((default.value() - 255.0).abs() < 2.0);
+    assert!((earth().surface_temperature(&sun(), one_au).value() - 288.0).abs() < 3.0);
+
+    // An iceball reflects most light and stays far colder than the fixed 0.3 albedo
+    let iceball = PlanetData {
+        body_type: BodyType::IceWorld,
+        ..earth()
+    };
+    let icy = iceball.surface_temperature(&sun(), one_au);
+    assert!(icy.value() < default.value() - 20.0, "{icy}");
+    assert!(iceball.is_ice_locked(&sun(), one_au));
+    assert!(!iceball.is_ice_locked(&sun(), Distance::<AstronomicalUnit>::new(0.5)));
+}
+
+#[test]
+fn test_gravitational_wave_inspiral() {
+    // Hulse-Taylor pulsar: two neutron stars, merging in ~300 Myr
+    let pulsar = star(1.441, SpectralType::D, LuminosityClass::VII);
+    let companion = star(1.387, SpectralType::D, LuminosityClass::VII);
+    let orbit = Orbit {
+        semi_major_axis: Distance::<Meter>::new(1.95e9).convert_to::<AstronomicalUnit>(),
+        eccentricity: 0.617,
+        ..Default::default()
+    };
+    let merger = orbit.gravitational_wave_inspiral_time(&pulsar, &companion);
+    assert!((merger.value() - 0.3).abs() < 0.03, "{merger}");
+
+    // A compact double white dwarf outlives any inspiral: at 0.013 AU the merger
+    // takes ~20 Gyr, longer than a Hubble time, and still happens
+    let white_dwarf = |mass| StarData {
+        mass: Mass::<SolarMass>::new(mass),
+        radius: Distance::<SunRadius>::new(0.0126),
+        temperature: Temperature::<Kelvin>::new(10_000.0),
+        luminosity: Power::<SolarLuminosity>::new(1e-3),
+        spectral_type: SpectralType::D,
+        luminosity_class: LuminosityClass::VII,
+    };
+    let mut compact = binary(white_dwarf(0.62), white_dwarf(0.58));
+    compact.roots[0].satellites[0].orbit = Some(Orbit {
+        semi_major_axis: Distance::<AstronomicalUnit>::new(0.013),
+        ..Default::default()
+    });
+    let merging = compact.merging_binaries();
+    assert_eq!(merging.len(), 1);
+    assert_eq!((merging[0].0.as_str(), merging[0].1.as_str()), ("A", "B"));
+    assert!(merging[0].2.value() > 13.8, "{}", merging[0].2);
+    assert!(
+        binary(white_dwarf(0.62), white_dwarf(0.58))
+            .merging_binaries()
+            .is_empty()
+    );
+
+    // The merging pair acts as one star for belt stability: a belt inside its
+    // P-type limit (~0.031 AU) survives, around a wide pair it would not
+    let mut rng = ChaCha8Rng::seed_from_u64(11);
+    let (inner, outer) = (
+        Distance::<AstronomicalUnit>::new(0.02),
+        Distance::<AstronomicalUnit>::new(0.025),
+    );
+    assert_eq!(
+        compact
+            .generate_asteroid_belt(inner, outer, 50, &mut rng)
+            .len(),
+        50
+    );
+    let mut wide = binary(white_dwarf(0.62), white_dwarf(0.58));
+    wide.roots[0].satellites[0].orbit = Some(Orbit {
+        semi_major_axis: Distance::<AstronomicalUnit>::new(0.5),
+        ..Default::default()
+    });
+    let (inner, outer) = (
+        Distance::<AstronomicalUnit>::new(0.8),
+        Distance::<AstronomicalUnit>::new(1.0),
+    );
+    assert!(wide.merging_binaries().is_empty());
+    assert!(
+        wide.generate_asteroid_belt(inner, outer, 50, &mut rng)
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_remnant_pairs_merge_after_main_sequence() {
+    // Neutron stars with the luminosity of a 1.4 M☉ main-sequence star
+    // (L ∝ M^3.5), whose main sequence would end after ~4 Gyr
+    let neutron_star = |mass: f64| StarData {
+        luminosity: Power::<SolarLuminosity>::new(mass.powf(3.5)),
+        ..star(mass, SpectralType::D, LuminosityClass::VII)
+    };
+    let hulse_taylor = Orbit {
+        semi_major_axis: Distance::<Meter>::new(1.95e9).convert_to::<AstronomicalUnit>(),
+        eccentricity: 0.617,
+        ..Default::default()
+    };
+    let mut pair = binary(neutron_star(1.441), neutron_star(1.387));
+    pair.age = Time::<Gigayear>::new(10.0);
+    pair.roots[0].satellites[0].orbit = Some(hulse_taylor);
+    assert!(neutron_star(1.441).main_sequence_lifetime().value() < pair.age.value());
+    assert_eq!(pair.merging_binaries().len(), 1);
+
+    // A main-sequence companion still bounds the pair by its own lifetime
+    let mut with_sun = binary(sun(), neutron_star(1.387));
+    with_sun.roots[0].satellites[0].orbit = Some(hulse_taylor);
+    assert_eq!(with_sun.merging_binaries().len(), 1);
+    with_sun.age = Time::<Gigayear>::new(10.0);
+    assert!(with_sun.merging_binaries().is_empty());
+}
+
+#[test]
+fn test_apsidal_precession() {
+    let mercury = StarData {
+        mass: Mass::<SolarMass>::new(1.66e-7),
+        radius: Distance::<SunRadius>::new(0.0035),
+        ..sun()
+    };
+    let orbit = Orbit {
+        semi_major_axis: Distance::<AstronomicalUnit>::new(0.387_098),
+        eccentricity: 0.2056,
+        ..Default::default()
+    };
+    // General relativity: ~43 arcseconds per century
+    let arcsec_per_century =
+        orbit.apsidal_precession_rate(&sun(), &mercury, false) * 3600.0 * 100.0;
+    assert!(
+        (arcsec_per_century - 43.0).abs() < 0.5,
+        "{arcsec_per_century}"
+    );
+
+    // Tidal bulges dominate for close stellar pairs
+    let close = Orbit {
+        semi_major_axis: Distance::<AstronomicalUnit>::new(0.05),
+        eccentricity: 0.1,
+        ..Default::default()
+    };
+    let relativistic = close.apsidal_precession_rate(&sun(), &sun(), false);
+    assert!(close.apsidal_precession_rate(&sun(), &sun(), true) > 2.0 * relativistic);
+}
+
+#[test]
+fn test_circular_velocity_in_astronomical_units() {
+    let earth_speed = Orbit::default().circular_velocity(Mass::<SolarMass>::new(1.0));
+    assert!((earth_speed.value() - 2.0 * std::f64::consts::PI).abs() < 1e-3);
+    let km_per_second = earth_speed.convert_to::<KilometerPerSecond>().value();
+    assert!((km_per_second - 29.8).abs() < 0.05, "{km_per_second}");
+}
+
+#[test]
+fn test_vis_viva_agrees_across_unit_systems() {
+    let mass = Mass::<SolarMass>::new(1.3);
+    let orbit = Orbit {
+        semi_major_axis: Distance::<AstronomicalUnit>::new(2.7),
+        eccentricity: 0.4,
+        ..Default::default()
+    };
+    let pairs = [
+        (
+            orbit.periapsis_distance(),
+            orbit.velocity_at_periapsis(mass),
+        ),
+        (orbit.apoapsis_distance(), orbit.velocity_at_apoapsis(mass)),
+    ];
+    for (distance, si) in pairs {
+        let astronomical = orbit
+            .velocity_at_distance(distance, mass)
+            .convert_to::<MeterPerSecond>();
+        assert!((astronomical.value() / si.value() - 1.0).abs() < 1e-3);
+    }
+
+    // A circular orbit moves at the same speed everywhere
+    let circular = Orbit::default();
+    let speed = circular.velocity_at_distance(Distance::<AstronomicalUnit>::new(1.0), mass);
+    assert!((speed.value() / circular.circular_velocity(mass).value() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_transit_light_curve() {
+    let system = |inclination: f64| SerializableStellarSystem {
+        schema_version: SerializableStellarSystem::SCHEMA_VERSION,
+        name: "Sol".to_string(),
+        age: Time::<Gigayear>::new(4.6),
+        roots: vec![SerializableBody {
+            name: "Sun".to_string(),
+            kind: BodyKind::Star(sun()),
+            orbit: None,
+            satellites: vec![SerializableBody {
+                name: "Earth".to_string(),
+                kind: BodyKind::Planet(earth()),
+                orbit: Some(Orbit {
+                    inclination: Angle::<Degree>::new(inclination).convert_to::<Radian>(),
+                    ..Default::default()
+                }),
+                satellites: vec![],
+            }],
+        }],
+        debris_disk: None,
+    };
+    let depth = transit_depth(sun().radius, earth().radius);
+    let min_flux = |curve: &[(Time<Day>, f64)]| {
+        curve
+            .iter()
+            .map(|&(_, flux)| flux)
+            .fold(f64::INFINITY, f64::min)
+    };
+
+    let curve = system(0.0).transit_light_curve(0, 10_000, None);
+    assert_eq!(curve.len(), 10_000);
+    assert!((min_flux(&curve) - (1.0 - depth)).abs() < 1e-12);
+    assert!((curve[9_999].0.value() - 365.25).abs() < 0.5);
+    let in_transit = curve.iter().filter(|&&(_, flux)| flux < 1.0).count();
+    assert!(in_transit > 5 && in_transit < 30, "{in_transit}");
+
+    // Limb darkening deepens the centre of the transit
+    let darkened = system(0.0).transit_light_curve(0, 10_000, Some(0.6));
+    assert!(min_flux(&darkened) < 1.0 - depth);
+
+    // A grazing miss never dims the star, and missing planets give no curve
+    assert_eq!(
+        min_flux(&system(10.0).transit_light_curve(0, 1_000, None)),
+        1.0
+    );
+    assert!(system(0.0).transit_light_curve(1, 100, None).is_empty());
+}
+
+#[test]
+fn test_magnetic_moment_for_core() {
+    let age = Time::<Gigayear>::new(4.6);
+    let analog = earth();
+    let sun_mass = Mass::<SolarMass>::new(1.0);
+    let one_au = Orbit::default();
+    let moment = analog.magnetic_moment_for_core(age, 0.325, sun_mass, &one_au);
+    assert!((moment / 8.0e22 - 1.0).abs() < 0.05);
+
+    // The same planet in the habitable zone of an M dwarf is locked to its
+    // ~15 day orbit within a million years and keeps only a weak dynamo
+    let m_dwarf_mass = Mass::<SolarMass>::new(0.2);
+    let close_in = Orbit {
+        semi_major_axis: Distance::<AstronomicalUnit>::new(0.07),
+        ..Default::default()
+    };
+    assert!(analog.tidal_locking_time(m_dwarf_mass, &close_in).value() < 1e-3);
+    assert!(analog.tidal_locking_time(sun_mass, &one_au).value() > 10.0);
+    let spin = analog.spin_period(m_dwarf_mass, &close_in, age);
+    assert!((spin.convert_to::<Day>().value() - 15.0).abs() < 1.0);
+    assert_eq!(analog.spin_period(sun_mass, &one_au, age).value(), 24.0);
+    let locked = analog.magnetic_moment_for_core(age, 0.325, m_dwarf_mass, &close_in);
+    assert!(locked < 0.3 * moment);
+
+    assert!(analog.magnetic_moment_for_core(age, 0.5, sun_mass, &one_au) > moment);
+    assert_eq!(
+        analog.magnetic_moment_for_core(age, 0.0, sun_mass, &one_au),
+        0.0
+    );
+}
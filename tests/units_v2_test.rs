@@ -31,6 +31,34 @@ fn test_unit_conversions() {
     assert!((in_earth_masses.value() - 333000.0).abs() < 1000.0);
 }
 
+#[test]
+fn test_temperature_offsets() {
+    let freezing = Temperature::<Celsius>::new(0.0).convert_to::<Kelvin>();
+    assert!((freezing.value() - 273.15).abs() < 1e-12);
+    let freezing = Temperature::<Fahrenheit>::new(32.0).convert_to::<Kelvin>();
+    assert!((freezing.value() - 273.15).abs() < 1e-9);
+
+    let boiling = Temperature::<Celsius>::new(100.0).convert_to::<Fahrenheit>();
+    assert!((boiling.value() - 212.0).abs() < 1e-9);
+    let absolute_zero = Temperature::<Kelvin>::new(0.0).convert_to::<Celsius>();
+    assert!((absolute_zero.value() + 273.15).abs() < 1e-12);
+
+    for value in [-40.0, 15.0, 288.0] {
+        let celsius = Temperature::<Celsius>::new(value);
+        let round_trip = celsius.convert_to::<Kelvin>().convert_to::<Celsius>();
+        assert!((round_trip.value() - value).abs() < 1e-9);
+        let fahrenheit = Temperature::<Fahrenheit>::new(value);
+        let round_trip = fahrenheit
+            .convert_to::<Celsius>()
+            .convert_to::<Fahrenheit>();
+        assert!((round_trip.value() - value).abs() < 1e-9);
+    }
+    // -40 is the same on both scales
+    let crossover = Temperature::<Celsius>::new(-40.0).convert_to::<Fahrenheit>();
+    assert!((crossover.value() + 40.0).abs() < 1e-9);
+    assert_eq!(format!("{}", Temperature::<Celsius>::new(15.0)), "15 °C");
+}
+
 #[test]
 fn test_arithmetic_operations() {
     let d1 = Distance::<Meter>::new(100.0);
@@ -57,6 +85,40 @@ fn test_arithmetic_operations() {
     assert_eq!(neg.value(), -100.0);
 }
 
+#[test]
+fn test_comparison() {
+    let sum = Distance::<AstronomicalUnit>::new(1.0) + Distance::<AstronomicalUnit>::new(0.5);
+    assert_eq!(sum, Distance::<AstronomicalUnit>::new(1.5));
+    assert!(sum > Distance::<AstronomicalUnit>::new(1.0));
+    assert!(sum * 2.0 >= Distance::<AstronomicalUnit>::new(3.0));
+    assert!(sum / 3.0 < Distance::<AstronomicalUnit>::new(0.6));
+
+    let nan = Distance::<AstronomicalUnit>::new(f64::NAN);
+    assert_ne!(nan, nan);
+    assert_eq!(nan.partial_cmp(&sum), None);
+}
+
+#[test]
+fn test_approx_eq() {
+    let a = Mass::<SolarMass>::new(1.0);
+    assert!(a.approx_eq(&Mass::<SolarMass>::new(1.0), 0.0));
+    assert!(Mass::<SolarMass>::new(0.0).approx_eq(&Mass::<SolarMass>::new(0.0), 0.0));
+
+    let close = Mass::<SolarMass>::new(1.05);
+    assert!(a.approx_eq(&close, 0.1));
+    assert!(close.approx_eq(&a, 0.1));
+    assert!(!a.approx_eq(&close, 0.01));
+    assert!(!a.approx_eq(&Mass::<SolarMass>::new(f64::NAN), 0.1));
+
+    let diff = a.abs_diff(&close);
+    assert!(diff.approx_eq(&Mass::<SolarMass>::new(0.05), 1e-9));
+    assert_eq!(close.abs_diff(&a), diff);
+
+    // The tolerance is relative to SI values, so offset units compare correctly
+    let body = Temperature::<Celsius>::new(37.0);
+    assert!(body.approx_eq(&Temperature::<Celsius>::new(38.0), 0.01));
+}
+
 #[test]
 fn test_dimensional_analysis() {
     let distance = Distance::<Meter>::new(100.0);
@@ -74,6 +136,30 @@ fn test_dimensional_analysis() {
     assert_eq!(result_si, 10.0);
 }
 
+#[test]
+fn test_dimensional_products() {
+    // Distance / Time has type Velocity, in SI base units
+    let speed: Velocity<MeterPerSecond> = Distance::<Kilometer>::new(36.0) / Time::<Hour>::new(1.0);
+    assert!((speed.value() - 10.0).abs() < 1e-12);
+
+    let acceleration: Acceleration<MeterPerSecondSquared> = speed / Time::<Second>::new(5.0);
+    assert!((acceleration.value() - 2.0).abs() < 1e-12);
+
+    let force: Force<Newton> = Mass::<Kilogram>::new(3.0) * acceleration;
+    let work: Energy<Joule> = force * Distance::<Meter>::new(4.0);
+    let power: Power<Watt> = work / Time::<Second>::new(2.0);
+    assert!((power.value() - 12.0).abs() < 1e-12);
+
+    let area: Area<SquareMeter> = Distance::<Meter>::new(2.0) * Distance::<Kilometer>::new(0.003);
+    let volume: Volume<CubicMeter> = area * Distance::<Meter>::new(0.5);
+    let density: Density<KilogramPerCubicMeter> = Mass::<Kilogram>::new(9.0) / volume;
+    assert!((density.value() - 3.0).abs() < 1e-12);
+
+    let travel: Time<Second> = Distance::<AstronomicalUnit>::new(1.0) / speed;
+    let back: Distance<Meter> = speed * travel;
+    assert!((back.value() / 149_597_870_700.0 - 1.0).abs() < 1e-12);
+}
+
 #[test]
 fn test_astronomical_units() {
     // Test stellar properties
@@ -91,6 +177,95 @@ fn test_astronomical_units() {
     assert!(age_s.value() > 1e17);
 }
 
+#[test]
+fn test_power_units() {
+    let sun = Power::<SolarLuminosity>::new(1.0);
+    assert!((sun.convert_to::<Watt>().value() - 3.828e26).abs() < 1e20);
+    assert!((sun.convert_to::<Kilowatt>().value() - 3.828e23).abs() < 1e17);
+
+    let heater = Power::<Watt>::Kilowatt(2.5);
+    assert!((heater.convert_to::<Watt>().value() - 2500.0).abs() < f64::EPSILON);
+    assert_eq!(format!("{}", heater), "2.5 kW");
+}
+
+#[test]
+fn test_parsing() {
+    let distance: Distance<AstronomicalUnit> = "1.5 AU".parse().unwrap();
+    assert_eq!(distance, Distance::<AstronomicalUnit>::new(1.5));
+    let mass: Mass<SolarMass> = "0.7 M☉".parse().unwrap();
+    assert_eq!(mass, Mass::<SolarMass>::new(0.7));
+    let age: Time<Gigayear> = "6.0 Gyr".parse().unwrap();
+    assert_eq!(age, Time::<Gigayear>::new(6.0));
+
+    // Parsing converts into the requested unit
+    let meters: Distance<Meter> = "2 km".parse().unwrap();
+    assert!((meters.value() - 2000.0).abs() < 1e-9);
+
+    assert_eq!(
+        "1.5 furlong".parse::<Distance<Meter>>(),
+        Err(ParseQuantityError::UnknownUnit("furlong".to_string()))
+    );
+    assert!(matches!(
+        "1.5".parse::<Distance<Meter>>(),
+        Err(ParseQuantityError::MissingUnit(_))
+    ));
+    assert!(matches!(
+        "abc AU".parse::<Distance<Meter>>(),
+        Err(ParseQuantityError::InvalidNumber(_))
+    ));
+}
+
+#[test]
+fn test_display_round_trip() {
+    let distance = Distance::<AstronomicalUnit>::new(5.2044);
+    assert_eq!(
+        format!("{}", distance).parse::<Distance<AstronomicalUnit>>(),
+        Ok(distance)
+    );
+    let mass = Mass::<EarthMass>::new(0.815);
+    assert_eq!(format!("{}", mass).parse::<Mass<EarthMass>>(), Ok(mass));
+    let period = Time::<Day>::new(365.256);
+    assert_eq!(format!("{}", period).parse::<Time<Day>>(), Ok(period));
+}
+
+#[test]
+fn test_pretty_string() {
+    assert_eq!(Distance::<Meter>::new(3e16).to_pretty_string(), "3.17 ly");
+    assert_eq!(Distance::<Meter>::new(5.0e11).to_pretty_string(), "3.34 AU");
+    assert_eq!(
+        Distance::<Kilometer>::new(12.5).to_pretty_string(),
+        "12.50 km"
+    );
+    assert_eq!(Distance::<Meter>::new(0.25).to_pretty_string(), "0.25 m");
+
+    assert_eq!(
+        Mass::<Kilogram>::new(1.989e30).to_pretty_string(),
+        "1.00 M☉"
+    );
+    assert_eq!(Mass::<EarthMass>::new(17.1).to_pretty_string(), "17.10 M⊕");
+
+    assert_eq!(Time::<Second>::new(90.0).to_pretty_string(), "1.50 min");
+    assert_eq!(Time::<Day>::new(730.5).to_pretty_string(), "2.00 yr");
+    assert_eq!(Time::<Year>::new(4.6e9).to_pretty_string(), "4.60 Gyr");
+
+    assert_eq!(Power::<Watt>::new(3.828e26).to_pretty_string(), "1.00 L☉");
+    assert_eq!(Power::<Watt>::new(2500.0).to_pretty_string(), "2.50 kW");
+}
+
+#[test]
+fn test_galactic_distances() {
+    let solar_circle = Distance::<Kiloparsec>::new(8.0);
+    let meters = solar_circle.convert_to::<Meter>();
+    assert!((meters.value() / 2.4686e20 - 1.0).abs() < 1e-4);
+    let back = meters.convert_to::<Kiloparsec>();
+    assert!((back.value() - 8.0).abs() < 1e-12);
+
+    let parsecs = solar_circle.convert_to::<Parsec>();
+    assert!((parsecs.value() - 8000.0).abs() < 1e-9);
+    assert_eq!(format!("{}", solar_circle), "8 kpc");
+    assert_eq!(format!("{}", Distance::<Parsec>::new(1.3)), "1.3 pc");
+}
+
 #[test]
 fn test_serialization() {
     let distance = Distance::<AstronomicalUnit>::new(1.5);
@@ -123,3 +298,26 @@ fn test_display_formatting() {
     assert!(mass_str.contains("M⊕"));
     assert!(power_str.contains("L☉"));
 }
+
+#[test]
+fn test_sum_and_mean() {
+    let masses = [
+        Mass::<SolarMass>::new(1.0),
+        Mass::<SolarMass>::new(1.0),
+        Mass::<SolarMass>::new(1.0),
+    ];
+    let total: Mass<SolarMass> = masses.iter().sum();
+    assert!((total.value() - 3.0).abs() < f64::EPSILON);
+    let total_owned: Mass<SolarMass> = masses.into_iter().sum();
+    assert!((total_owned.value() - 3.0).abs() < f64::EPSILON);
+
+    let separations = vec![
+        Distance::<AstronomicalUnit>::new(1.0),
+        Distance::<AstronomicalUnit>::new(3.0),
+    ];
+    let mean = separations.into_iter().mean().unwrap();
+    assert!((mean.value() - 2.0).abs() < f64::EPSILON);
+
+    let empty: Vec<Time<Gigayear>> = Vec::new();
+    assert!(empty.into_iter().mean().is_none());
+}